@@ -0,0 +1,149 @@
+use serde::Serialize;
+
+/// How much a step allocated: the number of allocations, and the most extra heap it had in use at once
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct Allocations {
+    pub count: usize,
+    pub peak_bytes: usize,
+}
+
+#[cfg(any(feature = "count-allocations", test))]
+pub(crate) use counting::measure;
+#[cfg(feature = "count-allocations")]
+pub use counting::CountingAllocator;
+
+/// Without the `count-allocations` feature there's nothing to measure with
+#[cfg(not(any(feature = "count-allocations", test)))]
+pub(crate) fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    (f(), None)
+}
+
+// always built for tests, so the allocator is tested without having to ask for the feature
+#[cfg(any(feature = "count-allocations", test))]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    use super::Allocations;
+
+    /// A global allocator that counts what each thread allocates, so `aoc time` can report it
+    ///
+    /// ```ignore
+    /// #[global_allocator]
+    /// static ALLOCATOR: aoc::CountingAllocator = aoc::CountingAllocator;
+    /// ```
+    pub struct CountingAllocator;
+
+    static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+    // per thread, so days timed in parallel don't count each other's allocations
+    struct Counters {
+        allocations: Cell<usize>,
+        current: Cell<isize>,
+        peak: Cell<isize>,
+    }
+
+    thread_local! {
+        static COUNTERS: Counters = const {
+            Counters {
+                allocations: Cell::new(0),
+                current: Cell::new(0),
+                peak: Cell::new(0),
+            }
+        };
+    }
+
+    fn record(change: isize, allocated: bool) {
+        // the thread local is gone while the thread is shutting down
+        let _ = COUNTERS.try_with(|counters| {
+            if allocated {
+                counters.allocations.set(counters.allocations.get() + 1);
+            }
+            let current = counters.current.get() + change;
+            counters.current.set(current);
+            counters.peak.set(counters.peak.get().max(current));
+        });
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            INSTALLED.store(true, Ordering::Relaxed);
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(layout.size() as isize, true);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            INSTALLED.store(true, Ordering::Relaxed);
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(layout.size() as isize, true);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(-(layout.size() as isize), false);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                record(new_size as isize - layout.size() as isize, true);
+            }
+            new
+        }
+    }
+
+    /// Runs `f`, measuring what it allocates on this thread.
+    /// Returns `None` for the allocations if [`CountingAllocator`] isn't the global allocator
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+        let (allocations, current) = COUNTERS.with(|counters| {
+            counters.peak.set(counters.current.get());
+            (counters.allocations.get(), counters.current.get())
+        });
+        let t = f();
+        let (after, peak) = COUNTERS.with(|counters| (counters.allocations.get(), counters.peak.get()));
+
+        let measured = INSTALLED.load(Ordering::Relaxed).then(|| Allocations {
+            count: after - allocations,
+            peak_bytes: (peak - current).max(0) as usize,
+        });
+        (t, measured)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{measure, CountingAllocator};
+        use crate::Allocations;
+
+        #[global_allocator]
+        static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+        #[test]
+        fn counts() {
+            let (_, allocations) = measure(|| {
+                let mut v = Vec::<u8>::with_capacity(1000);
+                v.extend([1, 2, 3]);
+                drop(v);
+                Box::new([0_u8; 500])
+            });
+            assert_eq!(
+                allocations,
+                Some(Allocations {
+                    count: 2,
+                    peak_bytes: 1000
+                })
+            );
+
+            let (_, allocations) = measure(|| 1 + 1);
+            assert_eq!(allocations, Some(Allocations::default()));
+        }
+    }
+}
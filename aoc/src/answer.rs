@@ -0,0 +1,92 @@
+use std::fmt;
+
+use crate::ChallengeError;
+
+/// A puzzle answer, as printed and submitted to Advent of Code
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(u64),
+    Signed(i64),
+    Big(u128),
+    String(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => n.fmt(f),
+            Answer::Signed(n) => n.fmt(f),
+            Answer::Big(n) => n.fmt(f),
+            Answer::String(s) => s.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident($inner:ty): $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $inner)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Integer(u64): u8, u16, u32, u64, usize);
+impl_from!(Signed(i64): i8, i16, i32, i64, isize);
+impl_from!(Big(u128): u128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::String(s.to_owned())
+    }
+}
+
+/// What a part can return: an answer, or a `Result` of one for parts that can fail
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, ChallengeError>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Result<Answer, ChallengeError> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>> IntoAnswer for Result<T, ChallengeError> {
+    fn into_answer(self) -> Result<Answer, ChallengeError> {
+        self.map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, IntoAnswer};
+    use crate::ChallengeError;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(1234_usize).to_string(), "1234");
+        assert_eq!(Answer::from(-12_i32).to_string(), "-12");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("EFJKZLBL").to_string(), "EFJKZLBL");
+    }
+
+    #[test]
+    fn into_answer() {
+        assert_eq!(7_u32.into_answer(), Ok(Answer::Integer(7)));
+        assert_eq!(Ok::<_, ChallengeError>(-3_i64).into_answer(), Ok(Answer::Signed(-3)));
+        assert_eq!(
+            Err::<u32, _>(ChallengeError::NoSolution).into_answer(),
+            Err(ChallengeError::NoSolution)
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{Answer, Description, Error, Leaderboard, SubmitOutcome};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/conradludgate/adventofcode2021";
+
+/// Talks to Advent of Code on behalf of a single session
+///
+/// ```no_run
+/// let input = aoc::AocClient::from_env().input(2021, 1);
+/// ```
+pub struct AocClient {
+    base_url: String,
+    session: Option<String>,
+    user_agent: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(session: Option<String>) -> Self {
+        AocClient {
+            base_url: BASE_URL.to_owned(),
+            session,
+            user_agent: USER_AGENT.to_owned(),
+            agent: ureq::agent(),
+        }
+    }
+
+    /// A client using `AOC_SESSION` from the environment or a `.env` file
+    pub fn from_env() -> Self {
+        Self::new(dotenv::var("AOC_SESSION").ok())
+    }
+
+    pub fn base_url(self, base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_owned();
+        AocClient { base_url, ..self }
+    }
+
+    pub fn user_agent(self, user_agent: impl Into<String>) -> Self {
+        let user_agent = user_agent.into();
+        AocClient { user_agent, ..self }
+    }
+
+    fn session(&self) -> Result<&str, Error> {
+        self.session.as_deref().ok_or(Error::MissingSession)
+    }
+
+    fn url(&self, year: usize, day: u32, path: &str) -> String {
+        format!("{}/{year}/day/{day}{path}", self.base_url)
+    }
+
+    fn request(&self, method: &str, url: &str) -> Result<ureq::Request, Error> {
+        Ok(self
+            .agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .set("User-Agent", &self.user_agent))
+    }
+
+    /// Fetches a page, relative to the day's URL
+    pub fn get(&self, year: usize, day: u32, path: &str) -> Result<String, Error> {
+        self.fetch(self.url(year, day, path))
+    }
+
+    fn fetch(&self, url: String) -> Result<String, Error> {
+        let response = self
+            .request("GET", &url)?
+            .call()
+            .map_err(|e| Error::from_ureq(&url, e))?;
+        response.into_string().map_err(|source| Error::Body { url, source })
+    }
+
+    /// Downloads the puzzle input for a day
+    pub fn input(&self, year: usize, day: u32) -> Result<String, Error> {
+        self.get(year, day, "/input")
+    }
+
+    /// Downloads the puzzle description for a day, with as many parts as are unlocked
+    pub fn description(&self, year: usize, day: u32) -> Result<Description, Error> {
+        self.get(year, day, "").map(|html| Description::from_html(&html))
+    }
+
+    /// Downloads a private leaderboard. Prefer [`leaderboard`](crate::leaderboard), which caches it
+    pub fn leaderboard(&self, year: usize, id: u64) -> Result<Leaderboard, Error> {
+        let url = format!("{}/{year}/leaderboard/private/view/{id}.json", self.base_url);
+        let json = self.fetch(url.clone())?;
+        serde_json::from_str(&json).map_err(|e| Error::Unexpected {
+            url,
+            message: e.to_string(),
+        })
+    }
+
+    pub fn submit(&self, year: usize, day: u32, level: usize, answer: &Answer) -> Result<SubmitOutcome, Error> {
+        let url = self.url(year, day, "/answer");
+        let response = self
+            .request("POST", &url)?
+            .send_form(&[("level", &level.to_string()), ("answer", &answer.to_string())])
+            .map_err(|e| Error::from_ureq(&url, e))?;
+
+        let html = response.into_string().map_err(|source| Error::Body { url, source })?;
+        Ok(SubmitOutcome::from_html(&html))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AocClient;
+    use crate::{test_server::TestServer, Error};
+
+    #[test]
+    fn get() {
+        let server = TestServer::serve(vec![(200, "1721\n979\n")]);
+        let client = AocClient::new(Some("abc123".to_owned()))
+            .base_url(format!("{}/", server.url))
+            .user_agent("tests");
+
+        assert_eq!(client.get(2020, 1, "/input").unwrap(), "1721\n979\n");
+
+        let request = server.request();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/2020/day/1/input");
+        assert_eq!(request.header("cookie"), Some("session=abc123"));
+        assert_eq!(request.header("user-agent"), Some("tests"));
+    }
+
+    #[test]
+    fn missing_session() {
+        let client = AocClient::new(None).base_url("http://127.0.0.1:1");
+        assert!(matches!(client.get(2021, 1, "/input"), Err(Error::MissingSession)));
+    }
+}
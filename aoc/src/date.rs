@@ -0,0 +1,66 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Puzzles unlock at midnight in UTC-5
+const RELEASE_OFFSET: i64 = -5 * 3600;
+
+/// Today's date in the puzzle release timezone, as (year, month, day)
+pub fn today() -> (i64, u32, u32) {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64 + RELEASE_OFFSET;
+    civil_from_days(secs.div_euclid(86400))
+}
+
+/// Unix timestamp of when a day's puzzle unlocks
+pub fn unlocks_at(year: i64, day: u32) -> i64 {
+    days_from_civil(year, 12, day) * 86400 - RELEASE_OFFSET
+}
+
+// the conversions between civil dates and days since the epoch are from
+// http://howardhinnant.github.io/date_algorithms.html
+
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{civil_from_days, days_from_civil, unlocks_at};
+
+    #[test]
+    fn civil() {
+        for (date, days) in [
+            ((1970, 1, 1), 0),
+            ((2000, 2, 29), 11016),
+            ((2021, 12, 25), 18986),
+            ((1969, 12, 31), -1),
+        ] {
+            assert_eq!(days_from_civil(date.0, date.1, date.2), days);
+            assert_eq!(civil_from_days(days), date);
+        }
+    }
+
+    #[test]
+    fn unlocks() {
+        // 2021-12-01T05:00:00Z and 2021-12-25T05:00:00Z
+        assert_eq!(unlocks_at(2021, 1), 1638334800);
+        assert_eq!(unlocks_at(2021, 25), 1640408400);
+    }
+}
@@ -0,0 +1,209 @@
+use scraper::{node::Node, ElementRef, Html, Selector};
+
+/// A puzzle description, one article per part that has been unlocked
+#[derive(Debug, PartialEq)]
+pub struct Description {
+    pub articles: Vec<Article>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Article {
+    pub title: String,
+    /// The whole article as Markdown, including its title
+    pub markdown: String,
+}
+
+impl Description {
+    /// Reads the `<article class="day-desc">` blocks out of a day's page
+    pub fn from_html(html: &str) -> Self {
+        let html = Html::parse_document(html);
+        let articles = Selector::parse("article.day-desc").unwrap();
+        let title = Selector::parse("h2").unwrap();
+
+        let articles = html
+            .select(&articles)
+            .map(|article| Article {
+                title: article
+                    .select(&title)
+                    .next()
+                    .map_or_else(String::new, |title| title.text().collect()),
+                markdown: markdown(article),
+            })
+            .collect();
+
+        Description { articles }
+    }
+
+    /// Splits a README written by [`Description::to_markdown`] back into its articles
+    pub fn from_markdown(markdown: &str) -> Self {
+        let mut articles: Vec<Article> = vec![];
+        let mut in_code = false;
+        for line in markdown.lines() {
+            in_code ^= line.starts_with("```");
+            if let Some(title) = line.strip_prefix("## ").filter(|_| !in_code) {
+                articles.push(Article {
+                    title: title.replace('\\', ""),
+                    markdown: String::new(),
+                });
+            }
+            if let Some(article) = articles.last_mut() {
+                article.markdown.push_str(line);
+                article.markdown.push('\n');
+            }
+        }
+        for article in &mut articles {
+            article.markdown = article.markdown.trim_end().to_owned();
+        }
+
+        Description { articles }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let articles: Vec<_> = self.articles.iter().map(|article| article.markdown.as_str()).collect();
+        articles.join("\n\n") + "\n"
+    }
+
+    /// Part two is only shown once part one has been solved
+    pub fn has_part_two(&self) -> bool {
+        self.articles.len() > 1
+    }
+}
+
+fn markdown(article: ElementRef) -> String {
+    let mut out = String::new();
+    blocks(article, &mut out);
+    out.trim_end().to_owned()
+}
+
+fn blocks(element: ElementRef, out: &mut String) {
+    for child in element.children().filter_map(ElementRef::wrap) {
+        let start = out.len();
+        match child.value().name() {
+            "h2" => {
+                out.push_str("## ");
+                escape_line_start(out, start + 3, child.text().collect::<String>());
+            }
+            "pre" => {
+                let code: String = child.text().collect();
+                out.push_str("```\n");
+                out.push_str(&code);
+                out.push_str("\n```");
+            }
+            "ul" => {
+                for item in child.children().filter_map(ElementRef::wrap) {
+                    out.push_str("* ");
+                    inline(item, out);
+                    out.push('\n');
+                }
+                out.pop();
+            }
+            _ => {
+                let mut line = String::new();
+                inline(child, &mut line);
+                escape_line_start(out, start, line.trim().to_owned());
+            }
+        }
+        out.push_str("\n\n");
+    }
+}
+
+/// Stops a block that happens to start like a list or heading from being read as one
+fn escape_line_start(out: &mut String, start: usize, text: String) {
+    out.push_str(&text);
+    if text.starts_with(['-', '+', '#', '>']) {
+        out.insert(start, '\\');
+    }
+}
+
+fn inline(element: ElementRef, out: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => escape(text, out),
+            Node::Element(_) => {
+                let child = ElementRef::wrap(child).unwrap();
+                match child.value().name() {
+                    "em" => {
+                        out.push_str("**");
+                        inline(child, out);
+                        out.push_str("**");
+                    }
+                    // emphasis can't go inside a code span, so hoist it out
+                    "code"
+                        if child
+                            .children()
+                            .any(|c| c.value().as_element().map(|e| e.name()) == Some("em")) =>
+                    {
+                        out.push_str("**`");
+                        out.extend(child.text());
+                        out.push_str("`**");
+                    }
+                    "code" => {
+                        out.push('`');
+                        out.extend(child.text());
+                        out.push('`');
+                    }
+                    "a" => {
+                        out.push('[');
+                        inline(child, out);
+                        out.push_str("](");
+                        out.push_str(child.value().attr("href").unwrap_or_default());
+                        out.push(')');
+                    }
+                    _ => inline(child, out),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn escape(text: &str, out: &mut String) {
+    for c in text.chars() {
+        if matches!(c, '*' | '_' | '`') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Description;
+    use crate::{test_server::TestServer, AocClient};
+
+    const PAGE: &str = include_str!("../fixtures/description/day01.html");
+    const README: &str = include_str!("../fixtures/description/day01.md");
+
+    #[test]
+    fn html_to_markdown() {
+        let description = Description::from_html(PAGE);
+        assert_eq!(description.articles.len(), 2);
+        assert_eq!(description.articles[0].title, "--- Day 1: Sonar Sweep ---");
+        assert_eq!(description.articles[1].title, "--- Part Two ---");
+        assert_eq!(description.to_markdown(), README);
+    }
+
+    #[test]
+    fn readme_round_trip() {
+        let description = Description::from_markdown(README);
+        assert_eq!(description, Description::from_html(PAGE));
+        assert!(description.has_part_two());
+    }
+
+    #[test]
+    fn part_one_only() {
+        let part_one = README.split("## \\--- Part Two").next().unwrap();
+        let description = Description::from_markdown(part_one);
+        assert_eq!(description.articles.len(), 1);
+        assert!(!description.has_part_two());
+    }
+
+    #[test]
+    fn download() {
+        let server = TestServer::serve(vec![(200, PAGE)]);
+        let client = AocClient::new(Some("abc123".to_owned())).base_url(&server.url);
+
+        assert_eq!(client.description(2021, 1).unwrap(), Description::from_html(PAGE));
+        assert_eq!(server.request().path, "/2021/day/1");
+    }
+}
@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Everything that can go wrong talking to Advent of Code, or keeping track of what we told it
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("AOC_SESSION is not set, add it to the environment or a .env file")]
+    MissingSession,
+    #[error("could not reach {url}: {message}")]
+    Network { url: String, message: String },
+    #[error("{url} responded with {status} {text}")]
+    Status { url: String, status: u16, text: String },
+    #[error("could not read the response from {url}: {source}")]
+    Body { url: String, source: std::io::Error },
+    #[error("{url} sent something unexpected: {message}")]
+    Unexpected { url: String, message: String },
+    #[error("could not access {}: {source}", path.display())]
+    Io { path: PathBuf, source: std::io::Error },
+    #[error("{} is not valid: {message}", path.display())]
+    Invalid { path: PathBuf, message: String },
+}
+
+/// Why a part of a challenge could not produce an answer
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ChallengeError {
+    #[error("no solution")]
+    NoSolution,
+    #[error("not implemented")]
+    Unimplemented,
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+}
+
+impl Error {
+    pub(crate) fn from_ureq(url: &str, error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => Error::Status {
+                url: url.to_owned(),
+                status,
+                text: response.status_text().to_owned(),
+            },
+            ureq::Error::Transport(transport) => Error::Network {
+                url: url.to_owned(),
+                message: transport.to_string(),
+            },
+        }
+    }
+}
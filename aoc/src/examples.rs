@@ -0,0 +1,293 @@
+use std::{fs, io, path::Path};
+
+use serde::Deserialize;
+
+use crate::{parse, run::solve, ChallengeError, Description, Error, Parser};
+
+/// The worked examples from a day's README, used to generate its example tests
+///
+/// Each part's input is the first code block of its article, and its answer is
+/// the last emphasised code span, as in "there are **`7`** measurements". Part
+/// two reuses part one's input, as the puzzles usually do. Where that guesses
+/// wrong, an `examples.toml` next to the README can override each part:
+///
+/// ```toml
+/// [part_one]
+/// # the third code block in the README, counting from 1
+/// block = 3
+///
+/// [part_two]
+/// input = """
+/// start-A
+/// A-end
+/// """
+/// answer = "3"
+///
+/// # or, for a part that doesn't match its example
+/// skip = true
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Examples {
+    pub part_one: Example,
+    pub part_two: Example,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Overrides {
+    #[serde(default)]
+    part_one: Override,
+    #[serde(default)]
+    part_two: Override,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Override {
+    input: Option<String>,
+    block: Option<usize>,
+    answer: Option<String>,
+    #[serde(default)]
+    skip: bool,
+}
+
+impl Examples {
+    /// Extracts the examples from a README written by [`Description::to_markdown`]
+    pub fn from_readme(readme: &str) -> Self {
+        let description = Description::from_markdown(readme);
+        let mut articles = description.articles.iter();
+
+        let part_one = articles.next().map_or_else(Example::default, |article| Example {
+            input: code_blocks(&article.markdown).next(),
+            answer: answers(&article.markdown).pop().map(str::to_owned),
+        });
+        let part_two = articles.next().map_or_else(Example::default, |article| Example {
+            input: part_one.input.clone(),
+            answer: answers(&article.markdown).pop().map(str::to_owned),
+        });
+
+        Examples { part_one, part_two }
+    }
+
+    /// Reads the examples for the day crate in `dir`, applying its `examples.toml` if it has one
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self, Error> {
+        let dir = dir.as_ref();
+        let readme = read(&dir.join("README.md"))?.unwrap_or_default();
+        let mut examples = Self::from_readme(&readme);
+        let path = dir.join("examples.toml");
+        if let Some(overrides) = read(&path)? {
+            let overrides = toml::from_str(&overrides).map_err(|e| Error::Invalid {
+                path,
+                message: e.to_string(),
+            })?;
+            examples.apply(&readme, overrides);
+        }
+        Ok(examples)
+    }
+
+    fn apply(&mut self, readme: &str, overrides: Overrides) {
+        let blocks: Vec<String> = code_blocks(readme).collect();
+
+        self.part_one.apply(&blocks, overrides.part_one);
+        if self.part_two.input.is_some() {
+            self.part_two.input = self.part_one.input.clone();
+        }
+        self.part_two.apply(&blocks, overrides.part_two);
+    }
+
+    pub fn part(&self, level: usize) -> &Example {
+        match level {
+            1 => &self.part_one,
+            2 => &self.part_two,
+            _ => panic!("there are only two parts, not {level}"),
+        }
+    }
+
+    /// Runs a part against its example. Parts without one, like those not yet in the README, are skipped
+    pub fn check<'i, P: Parser<'i>>(&'i self, level: usize) {
+        let example = self.part(level);
+        let (input, expected) = match (&example.input, &example.answer) {
+            (Some(input), Some(answer)) => (input, answer),
+            (None, None) => return eprintln!("{} part {level} has no example to check", P::NAME),
+            _ => panic!("could not find the part {level} example, add it to examples.toml"),
+        };
+
+        let challenge = parse::<P>(input).unwrap_or_else(|e| panic!("{e}"));
+        let answer = match solve(&challenge, level) {
+            Ok(answer) => answer,
+            Err(ChallengeError::Unimplemented) => return eprintln!("{} part {level} is not implemented", P::NAME),
+            Err(e) => panic!("{} part {level} example: {e}", P::NAME),
+        };
+        assert_eq!(answer.to_string(), *expected, "{} part {level} example", P::NAME);
+    }
+}
+
+impl Example {
+    fn apply(&mut self, blocks: &[String], overrides: Override) {
+        if overrides.skip {
+            *self = Example::default();
+            return;
+        }
+        if let Some(block) = overrides.block {
+            let block = blocks.get(block.wrapping_sub(1));
+            self.input = Some(
+                block
+                    .expect("examples.toml refers to a code block not in the README")
+                    .clone(),
+            );
+        }
+        self.input = overrides.input.or(self.input.take());
+        self.answer = overrides.answer.or(self.answer.take());
+    }
+}
+
+/// Reads a file that may not have been written yet
+fn read(path: &Path) -> Result<Option<String>, Error> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(Error::Io {
+            path: path.to_owned(),
+            source,
+        }),
+    }
+}
+
+fn code_blocks(markdown: &str) -> impl Iterator<Item = String> + '_ {
+    let mut lines = markdown.lines();
+    std::iter::from_fn(move || {
+        lines.by_ref().find(|line| line.starts_with("```"))?;
+        let block: Vec<_> = lines.by_ref().take_while(|line| !line.starts_with("```")).collect();
+        Some(block.join("\n").trim_end().to_owned() + "\n")
+    })
+}
+
+/// Every emphasised code span outside of code blocks
+///
+/// READMEs written by [`Description::to_markdown`] emphasise around the code,
+/// as in "**`7`**", while older ones emphasise inside it, as in "`1749 - 161 = **1588**`".
+fn answers(markdown: &str) -> Vec<&str> {
+    let mut answers = vec![];
+    let mut in_code = false;
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_code = !in_code;
+        }
+        if in_code || line.starts_with("```") {
+            continue;
+        }
+
+        // odd segments are the insides of code spans
+        let segments: Vec<&str> = line.split('`').collect();
+        for i in (1..segments.len() - 1).step_by(2) {
+            let span = segments[i];
+            if segments[i - 1].ends_with("**") && segments[i + 1].starts_with("**") {
+                answers.push(span);
+            } else if let Some((_, answer)) = span.strip_suffix("**").and_then(|span| span.rsplit_once("**")) {
+                answers.push(answer);
+            }
+        }
+    }
+    answers
+}
+
+/// Generates a test per part that runs the challenge against the examples in its README
+///
+/// ```ignore
+/// aoc::examples!(Day01);
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($challenge:ty) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            #[test]
+            fn part_one() {
+                let examples = $crate::Examples::load(env!("CARGO_MANIFEST_DIR")).unwrap_or_else(|e| panic!("{e}"));
+                examples.check::<$challenge>(1);
+            }
+
+            #[test]
+            fn part_two() {
+                let examples = $crate::Examples::load(env!("CARGO_MANIFEST_DIR")).unwrap_or_else(|e| panic!("{e}"));
+                examples.check::<$challenge>(2);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{Example, Examples};
+    use crate::Error;
+
+    const README: &str = include_str!("../fixtures/description/day01.md");
+
+    fn example(input: &str, answer: &str) -> Example {
+        Example {
+            input: Some(input.to_owned()),
+            answer: Some(answer.to_owned()),
+        }
+    }
+
+    #[test]
+    fn from_readme() {
+        let input = "199\n200\n208\n210\n200\n";
+        assert_eq!(
+            Examples::from_readme(README),
+            Examples {
+                part_one: example(input, "7"),
+                part_two: example(input, "5"),
+            }
+        );
+    }
+
+    #[test]
+    fn part_one_only() {
+        let part_one = README.split("## \\--- Part Two").next().unwrap();
+        let examples = Examples::from_readme(part_one);
+        assert_eq!(examples.part_one.answer.as_deref(), Some("7"));
+        assert_eq!(examples.part_two, Example::default());
+    }
+
+    #[test]
+    fn overrides() {
+        let mut examples = Examples::from_readme(README);
+        let overrides = toml::from_str("[part_one]\nblock = 2\n[part_two]\nanswer = \"6\"").unwrap();
+        examples.apply(README, overrides);
+
+        let block = "199 (N/A - no previous measurement)\n200 (increased)\n208 (increased)\n";
+        assert_eq!(examples.part_one, example(block, "7"));
+        assert_eq!(examples.part_two, example(block, "6"));
+    }
+
+    #[test]
+    fn skip() {
+        let mut examples = Examples::from_readme(README);
+        examples.apply(README, toml::from_str("[part_two]\nskip = true").unwrap());
+
+        assert_eq!(examples.part_one.answer.as_deref(), Some("7"));
+        assert_eq!(examples.part_two, Example::default());
+    }
+
+    #[test]
+    fn load() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("README.md"), README).unwrap();
+        assert_eq!(Examples::load(&dir).unwrap(), Examples::from_readme(README));
+
+        fs::write(dir.join("examples.toml"), "[part_one\n").unwrap();
+        assert!(matches!(Examples::load(&dir), Err(Error::Invalid { .. })));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,14 @@
+use rand::RngCore;
+
+use crate::Challenge;
+
+/// A challenge that can make up valid inputs of any size, for stress tests and scaling benchmarks
+///
+/// What the size means is up to each day, such as the side of a grid or the number of lines.
+/// Register it with `aoc::day!(Day09, generate)` so `aoc generate` can find it.
+pub trait Generate: Challenge {
+    fn generate(rng: &mut dyn RngCore, size: usize) -> String;
+}
+
+/// [`Generate::generate`] for one challenge, as stored in a [`Day`](crate::Day)
+pub type Generator = fn(&mut dyn RngCore, usize) -> String;
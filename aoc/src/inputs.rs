@@ -0,0 +1,109 @@
+use std::{fs, io, path::Path};
+
+use crate::{challenge_dir, Error, Expected};
+
+/// An extra puzzle input from `challenges/<YEAR>/<NAME>/inputs/`, such as a teammate's
+///
+/// Each `inputs/<name>.txt` can have its accepted answers alongside it in
+/// `inputs/<name>.toml`, with the same `part_one` and `part_two` keys as
+/// `answers.toml`.
+#[derive(Debug, PartialEq)]
+pub struct Input {
+    pub name: String,
+    pub text: String,
+    pub expected: Expected,
+}
+
+/// Reads the extra inputs for a challenge, in name order
+pub fn inputs(year: usize, name: &str) -> Result<Vec<Input>, Error> {
+    read_inputs(&challenge_dir(year, name).join("inputs"))
+}
+
+fn read_inputs(dir: &Path) -> Result<Vec<Input>, Error> {
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |source| Error::Io { path, source }
+    };
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(source) => return Err(io_error(dir)(source)),
+    };
+
+    let mut inputs = vec![];
+    for entry in entries {
+        let path = entry.map_err(io_error(dir))?.path();
+        if path.extension() != Some("txt".as_ref()) {
+            continue;
+        }
+
+        let text = fs::read_to_string(&path).map_err(io_error(&path))?;
+        let answers = path.with_extension("toml");
+        let expected = match fs::read_to_string(&answers) {
+            Ok(expected) => toml::from_str(&expected).map_err(|e| Error::Invalid {
+                path: answers,
+                message: e.to_string(),
+            })?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Expected::default(),
+            Err(source) => return Err(io_error(&answers)(source)),
+        };
+
+        inputs.push(Input {
+            name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+            text,
+            expected,
+        });
+    }
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{read_inputs, Input};
+    use crate::{Error, Expected};
+
+    #[test]
+    fn reads_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("bob.txt"), "target area: x=20..30, y=-10..-5\n").unwrap();
+        fs::write(dir.join("alice.txt"), "target area: x=155..182, y=-117..-67\n").unwrap();
+        fs::write(dir.join("alice.toml"), "part_one = \"6786\"\n").unwrap();
+        fs::write(dir.join("notes.md"), "not an input").unwrap();
+
+        let inputs = read_inputs(&dir).unwrap();
+        assert_eq!(
+            inputs,
+            [
+                Input {
+                    name: "alice".to_owned(),
+                    text: "target area: x=155..182, y=-117..-67\n".to_owned(),
+                    expected: Expected {
+                        part_one: Some("6786".to_owned()),
+                        part_two: None,
+                    },
+                },
+                Input {
+                    name: "bob".to_owned(),
+                    text: "target area: x=20..30, y=-10..-5\n".to_owned(),
+                    expected: Expected::default(),
+                },
+            ]
+        );
+
+        fs::write(dir.join("bob.toml"), "part_one = 45").unwrap();
+        assert!(matches!(read_inputs(&dir), Err(Error::Invalid { .. })));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn no_inputs() {
+        assert_eq!(read_inputs("does/not/exist".as_ref()).unwrap(), []);
+    }
+}
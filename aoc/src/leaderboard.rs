@@ -0,0 +1,256 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{date, AocClient, Error};
+
+/// Advent of Code asks that a private leaderboard is fetched at most once every 15 minutes
+const CACHE_FOR: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard, as served by `/<YEAR>/leaderboard/private/view/<ID>.json`
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    /// Keyed by member ID
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Members who haven't set a name show up as anonymous
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    /// Unix timestamp of the latest star, or 0 for none
+    pub last_star_ts: i64,
+    /// The stars collected, by day and then by part
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u32, BTreeMap<u32, Star>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Star {
+    /// Unix timestamp of when the part was solved
+    pub get_star_ts: i64,
+}
+
+impl Leaderboard {
+    /// Members from first to last place: by local score, then whoever got there first
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_by_key(|member| (std::cmp::Reverse(member.local_score), member.last_star_ts, member.id));
+        members
+    }
+
+    /// Unix timestamp of when a day's puzzle unlocked, or `None` if the event isn't named after its year
+    pub fn unlocked(&self, day: u32) -> Option<i64> {
+        let year = self.event.parse().ok()?;
+        Some(date::unlocks_at(year, day))
+    }
+}
+
+impl Member {
+    /// The member's name, or how the leaderboard shows members without one
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// Unix timestamp of when the member solved a part, if they have
+    pub fn solved(&self, day: u32, level: u32) -> Option<i64> {
+        let star = self.completion_day_level.get(&day)?.get(&level)?;
+        Some(star.get_star_ts)
+    }
+}
+
+/// Where a leaderboard is cached between fetches. Kept out of git, since it names the members
+pub fn leaderboard_file(year: usize, id: u64) -> PathBuf {
+    Path::new("challenges")
+        .join(year.to_string())
+        .join(format!("leaderboard-{id}.json"))
+}
+
+/// Reads a private leaderboard, only downloading it again once the cached copy is 15 minutes old
+pub fn leaderboard(client: &AocClient, year: usize, id: u64) -> Result<Leaderboard, Error> {
+    cached_leaderboard(client, year, id, &leaderboard_file(year, id))
+}
+
+fn cached_leaderboard(client: &AocClient, year: usize, id: u64, file: &Path) -> Result<Leaderboard, Error> {
+    let io_error = |source| Error::Io {
+        path: file.to_owned(),
+        source,
+    };
+
+    let age = fs::metadata(file)
+        .and_then(|metadata| metadata.modified())
+        .map(|modified| modified.elapsed());
+    match age {
+        Ok(Ok(age)) if age < CACHE_FOR => {
+            let json = fs::read_to_string(file).map_err(io_error)?;
+            return serde_json::from_str(&json).map_err(|e| Error::Invalid {
+                path: file.to_owned(),
+                message: e.to_string(),
+            });
+        }
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(io_error(e)),
+        // missing, stale, or modified in the future
+        _ => {}
+    }
+
+    eprintln!("Downloading the leaderboard to {}", file.display());
+    let leaderboard = client.leaderboard(year, id)?;
+    let json = serde_json::to_string_pretty(&leaderboard).expect("leaderboard should always serialize");
+    fs::write(file, json).map_err(io_error)?;
+    Ok(leaderboard)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        time::{Duration, SystemTime},
+    };
+
+    use super::{cached_leaderboard, Leaderboard, Star};
+    use crate::{test_server::TestServer, AocClient, Error};
+
+    const JSON: &str = r#"{
+        "event": "2021",
+        "owner_id": 12,
+        "members": {
+            "12": {
+                "id": 12,
+                "name": "alice",
+                "stars": 3,
+                "local_score": 7,
+                "global_score": 0,
+                "last_star_ts": 1638418000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1638335000, "star_index": 1},
+                        "2": {"get_star_ts": 1638335600, "star_index": 2}
+                    },
+                    "2": {"1": {"get_star_ts": 1638418000, "star_index": 5}}
+                }
+            },
+            "34": {
+                "id": 34,
+                "name": null,
+                "stars": 0,
+                "local_score": 0,
+                "global_score": 0,
+                "last_star_ts": 0,
+                "completion_day_level": {}
+            },
+            "56": {
+                "id": 56,
+                "name": "bob",
+                "stars": 2,
+                "local_score": 7,
+                "global_score": 0,
+                "last_star_ts": 1638340000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1638336000, "star_index": 3},
+                        "2": {"get_star_ts": 1638340000, "star_index": 4}
+                    }
+                }
+            }
+        }
+    }"#;
+
+    fn client(server: &TestServer) -> AocClient {
+        AocClient::new(Some("abc123".to_owned())).base_url(&server.url)
+    }
+
+    #[test]
+    fn parse() {
+        let leaderboard: Leaderboard = serde_json::from_str(JSON).unwrap();
+        let ranked: Vec<_> = leaderboard
+            .ranked()
+            .iter()
+            .map(|member| member.display_name())
+            .collect();
+        assert_eq!(ranked, ["bob", "alice", "(anonymous user #34)"]);
+
+        let alice = &leaderboard.members["12"];
+        assert_eq!(alice.solved(1, 2), Some(1638335600));
+        assert_eq!(alice.solved(2, 2), None);
+        assert_eq!(
+            alice.completion_day_level[&2][&1],
+            Star {
+                get_star_ts: 1638418000
+            }
+        );
+
+        // 2021-12-01T05:00:00Z
+        assert_eq!(leaderboard.unlocked(1), Some(1638334800));
+        let renamed = Leaderboard {
+            event: "winter".to_owned(),
+            ..leaderboard
+        };
+        assert_eq!(renamed.unlocked(1), None);
+    }
+
+    #[test]
+    fn fetch() {
+        let server = TestServer::serve(vec![(200, JSON)]);
+        let leaderboard = client(&server).leaderboard(2021, 12).unwrap();
+        assert_eq!(leaderboard.members.len(), 3);
+
+        let request = server.request();
+        assert_eq!(request.path, "/2021/leaderboard/private/view/12.json");
+        assert_eq!(request.header("cookie"), Some("session=abc123"));
+
+        // without access, the leaderboard page is served instead
+        let server = TestServer::serve(vec![(200, "<html>private leaderboards</html>")]);
+        assert!(matches!(
+            client(&server).leaderboard(2021, 12),
+            Err(Error::Unexpected { .. })
+        ));
+    }
+
+    #[test]
+    fn cache() {
+        let dir = std::env::temp_dir().join(format!("aoc-leaderboard-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("leaderboard-12.json");
+
+        // the second read comes from the cache, since the server only answers once
+        let server = TestServer::serve(vec![(200, JSON)]);
+        let fetched = cached_leaderboard(&client(&server), 2021, 12, &file).unwrap();
+        let cached = cached_leaderboard(&client(&server), 2021, 12, &file).unwrap();
+        assert_eq!(fetched, cached);
+        server.request();
+
+        // once it's 15 minutes old it's downloaded again
+        let stale = SystemTime::now() - Duration::from_secs(16 * 60);
+        fs::File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(stale)
+            .unwrap();
+        let json = JSON.replace(r#""name": "bob""#, r#""name": "carol""#).leak();
+        let server = TestServer::serve(vec![(200, json)]);
+        let refreshed = cached_leaderboard(&client(&server), 2021, 12, &file).unwrap();
+        assert_eq!(refreshed.members["56"].name.as_deref(), Some("carol"));
+        server.request();
+
+        fs::write(&file, "{").unwrap();
+        assert!(matches!(
+            cached_leaderboard(&client(&server), 2021, 12, &file),
+            Err(Error::Invalid { .. })
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,241 @@
+use std::{fmt, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Answer, Error, Hint, SubmitOutcome};
+
+/// A record of every answer submitted for a day, and what Advent of Code made of it
+///
+/// Stored as `challenges/<YEAR>/<NAME>/ledger.toml`, so that reruns never resubmit
+/// an answer we already know the verdict of.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part_one: Vec<Entry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part_two: Vec<Entry>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+}
+
+/// Why an answer should not be submitted
+#[derive(Debug, PartialEq)]
+pub enum Refusal {
+    /// The part is solved, with the given answer
+    AlreadyCorrect(String),
+    /// This exact answer was already rejected
+    KnownWrong,
+    /// The answer is at or above one that was too high
+    TooHigh(String),
+    /// The answer is at or below one that was too low
+    TooLow(String),
+}
+
+impl Ledger {
+    /// Reads a ledger, or starts an empty one if the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(ledger) => toml::from_str(&ledger).map_err(|e| Error::Invalid {
+                path: path.to_owned(),
+                message: e.to_string(),
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(Error::Io {
+                path: path.to_owned(),
+                source,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let ledger = toml::to_string(self).expect("ledger should always serialize");
+        fs::write(path, ledger).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    pub fn part(&self, level: usize) -> &[Entry] {
+        match level {
+            1 => &self.part_one,
+            2 => &self.part_two,
+            _ => panic!("there are only two parts, not {level}"),
+        }
+    }
+
+    fn part_mut(&mut self, level: usize) -> &mut Vec<Entry> {
+        match level {
+            1 => &mut self.part_one,
+            2 => &mut self.part_two,
+            _ => panic!("there are only two parts, not {level}"),
+        }
+    }
+
+    /// The accepted answer for a part, if there is one
+    pub fn correct(&self, level: usize) -> Option<&str> {
+        self.part(level)
+            .iter()
+            .find(|entry| entry.verdict == Verdict::Correct)
+            .map(|entry| entry.answer.as_str())
+    }
+
+    /// Checks an answer against everything we already know about this part
+    pub fn check(&self, level: usize, answer: &Answer) -> Result<(), Refusal> {
+        if let Some(correct) = self.correct(level) {
+            return Err(Refusal::AlreadyCorrect(correct.to_owned()));
+        }
+
+        let answer = answer.to_string();
+        let number = answer.parse::<i128>().ok();
+        for entry in self.part(level) {
+            let bound = entry.answer.parse::<i128>().ok();
+            match (entry.verdict, number, bound) {
+                (Verdict::TooHigh, Some(n), Some(bound)) if n >= bound => {
+                    return Err(Refusal::TooHigh(entry.answer.clone()))
+                }
+                (Verdict::TooLow, Some(n), Some(bound)) if n <= bound => {
+                    return Err(Refusal::TooLow(entry.answer.clone()))
+                }
+                _ if entry.answer == answer => return Err(Refusal::KnownWrong),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the verdict on a submitted answer. Outcomes that aren't verdicts are ignored
+    pub fn record(&mut self, level: usize, answer: &Answer, outcome: &SubmitOutcome) {
+        let verdict = match outcome {
+            SubmitOutcome::Correct => Verdict::Correct,
+            SubmitOutcome::Incorrect { hint: None } => Verdict::Incorrect,
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => Verdict::TooHigh,
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => Verdict::TooLow,
+            _ => return,
+        };
+
+        self.part_mut(level).push(Entry {
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(correct) => write!(f, "already solved with {correct}"),
+            Refusal::KnownWrong => write!(f, "this answer was already rejected"),
+            Refusal::TooHigh(bound) => write!(f, "{bound} was already too high"),
+            Refusal::TooLow(bound) => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Entry, Ledger, Refusal, Verdict};
+    use crate::{Answer, Hint, SubmitOutcome};
+
+    fn incorrect(hint: Option<Hint>) -> SubmitOutcome {
+        SubmitOutcome::Incorrect { hint }
+    }
+
+    #[test]
+    fn refuses_known_wrong() {
+        let mut ledger = Ledger::default();
+        ledger.record(1, &Answer::from("ABCD"), &incorrect(None));
+
+        assert_eq!(ledger.check(1, &Answer::from("ABCD")), Err(Refusal::KnownWrong));
+        assert_eq!(ledger.check(1, &Answer::from("ABCE")), Ok(()));
+        assert_eq!(ledger.check(2, &Answer::from("ABCD")), Ok(()));
+    }
+
+    #[test]
+    fn refuses_out_of_bounds() {
+        let mut ledger = Ledger::default();
+        ledger.record(1, &Answer::from(1000_usize), &incorrect(Some(Hint::TooHigh)));
+        ledger.record(1, &Answer::from(200_usize), &incorrect(Some(Hint::TooLow)));
+
+        let high = Err(Refusal::TooHigh("1000".to_owned()));
+        let low = Err(Refusal::TooLow("200".to_owned()));
+        assert_eq!(ledger.check(1, &Answer::from(1000_usize)), high);
+        assert_eq!(ledger.check(1, &Answer::from(2000_usize)), high);
+        assert_eq!(ledger.check(1, &Answer::from(200_usize)), low);
+        assert_eq!(ledger.check(1, &Answer::from(-5_i32)), low);
+        assert_eq!(ledger.check(1, &Answer::from(500_usize)), Ok(()));
+    }
+
+    #[test]
+    fn skips_solved() {
+        let mut ledger = Ledger::default();
+        ledger.record(1, &Answer::from(300_usize), &incorrect(Some(Hint::TooLow)));
+        ledger.record(1, &Answer::from(352_usize), &SubmitOutcome::Correct);
+
+        assert_eq!(ledger.correct(1), Some("352"));
+        let solved = Err(Refusal::AlreadyCorrect("352".to_owned()));
+        assert_eq!(ledger.check(1, &Answer::from(352_usize)), solved);
+        assert_eq!(ledger.check(1, &Answer::from(400_usize)), solved);
+    }
+
+    #[test]
+    fn ignores_non_verdicts() {
+        let mut ledger = Ledger::default();
+        ledger.record(1, &Answer::from(1_usize), &SubmitOutcome::RateLimited { wait: None });
+        ledger.record(1, &Answer::from(1_usize), &SubmitOutcome::WrongLevel);
+
+        assert_eq!(ledger, Ledger::default());
+    }
+
+    #[test]
+    fn toml() {
+        let ledger: Ledger = toml::from_str(
+            r#"
+            [[part_one]]
+            answer = "1000"
+            verdict = "too_high"
+
+            [[part_one]]
+            answer = "352"
+            verdict = "correct"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            ledger.part_one,
+            [
+                Entry {
+                    answer: "1000".to_owned(),
+                    verdict: Verdict::TooHigh
+                },
+                Entry {
+                    answer: "352".to_owned(),
+                    verdict: Verdict::Correct
+                },
+            ]
+        );
+        assert!(ledger.part_two.is_empty());
+        assert_eq!(
+            toml::from_str::<Ledger>(&toml::to_string(&ledger).unwrap()).unwrap(),
+            ledger
+        );
+    }
+}
@@ -1,54 +1,143 @@
-use std::path::Path;
+mod allocations;
+mod answer;
+mod client;
+mod date;
+mod description;
+mod error;
+mod examples;
+mod generate;
+mod inputs;
+mod leaderboard;
+mod ledger;
+mod parse;
+mod run;
+mod submit;
+#[cfg(test)]
+mod test_server;
+mod timing;
+mod unparse;
+mod verify;
+mod visualize;
 
-const YEAR: usize = 2021;
+pub use allocations::Allocations;
+#[cfg(feature = "count-allocations")]
+pub use allocations::CountingAllocator;
+pub use answer::{Answer, IntoAnswer};
+pub use client::AocClient;
+pub use date::{today, unlocks_at};
+pub use description::{Article, Description};
+pub use error::{ChallengeError, Error};
+pub use examples::{Example, Examples};
+pub use generate::{Generate, Generator};
+pub use inputs::{inputs, Input};
+pub use leaderboard::{leaderboard, leaderboard_file, Leaderboard, Member, Star};
+pub use ledger::{Entry, Ledger, Refusal, Verdict};
+pub use parse::{parse, Location, ParseError};
+pub use run::{challenge_dir, load, run, Mode, Parts, RunOptions};
+pub use submit::{Hint, SubmitOutcome};
+pub use timing::{time, PhaseAllocations, Timings};
+pub use unparse::{assert_roundtrip, Unparse};
+pub use verify::{check, verify, Answers, Check, Expected};
+pub use visualize::{visualize, Visualize, VisualizeError, Visualizer};
+/// Re-exported so solutions can generate inputs and emit debug events without depending on these themselves
+pub use {rand, tracing};
 
 pub trait Parser<'i>: Sized + Challenge {
     fn parse(input: &'i str) -> nom::IResult<&'i str, Self>;
 }
 
 pub trait Challenge {
+    /// The day, as `dayNN`
     const NAME: &'static str;
+    /// The event the day belongs to
+    const YEAR: usize;
 
-    fn part_one(self) -> usize;
-    fn part_two(self) -> usize;
-}
+    /// What each part returns, either an answer or a `Result<_, ChallengeError>` of one
+    type Output1: IntoAnswer;
+    type Output2: IntoAnswer;
 
-pub fn load<C: Challenge>() -> String {
-    println!("\nRunning challenge {}", C::NAME);
+    fn part_one(&self) -> Self::Output1;
+    fn part_two(&self) -> Self::Output2;
+}
 
-    let file = Path::new("challenges").join(C::NAME).join("input.txt");
-    std::fs::read_to_string(file).expect("could not read file")
+/// A type-erased handle to a challenge, so that a single binary can load and run any day
+pub struct Day {
+    pub name: &'static str,
+    pub year: usize,
+    pub load: fn(&AocClient) -> Result<String, Error>,
+    pub run: fn(&AocClient, &str, &RunOptions, &mut Vec<String>) -> bool,
+    pub time: fn(&str) -> Result<Timings, ParseError>,
+    pub verify: fn(&str, &Expected) -> Result<[Check; 2], ParseError>,
+    /// Solves a single part, like [`check`]
+    pub check: fn(&str, usize, Option<&str>) -> Result<Check, ParseError>,
+    /// Plays the challenge's frames, for days that implement [`Visualize`]
+    pub visualize: Option<Visualizer>,
+    /// Makes up inputs, for days that implement [`Generate`]
+    pub generate: Option<Generator>,
 }
 
-pub fn run<'i, P: Parser<'i>>(input: &'i str) {
-    let challenge = P::parse(input).unwrap().1;
-
-    let file = Path::new("challenges").join(P::NAME).join("README.md");
-    let readme = std::fs::read_to_string(file).expect("could not read file");
-    let part_one = !readme.contains("--- Part Two ---");
-
-    if part_one {
-        let p1 = challenge.part_one();
-        println!("\tAnswer to part one: {}", p1);
-        submit::<P>(1, p1);
-    } else {
-        let p2 = challenge.part_two();
-        println!("\tAnswer to part two: {}\n", p2);
-        submit::<P>(2, p2);
+impl Day {
+    /// The day of the month, taken from the challenge name (`day07` is day 7)
+    pub fn number(&self) -> u32 {
+        self.name[3..]
+            .parse()
+            .expect("challenge names should be of the form dayNN")
     }
-}
 
-fn submit<C: Challenge>(level: usize, answer: usize) {
-    let session = dotenv::var("AOC_SESSION").unwrap();
+    /// The extra inputs for this day, besides its own `input.txt`
+    pub fn inputs(&self) -> Result<Vec<Input>, Error> {
+        inputs(self.year, self.name)
+    }
+
+    /// A span to run everything for this day in, so its events can be told apart
+    pub fn span(&self) -> tracing::Span {
+        tracing::info_span!("day", year = self.year, name = self.name)
+    }
 
-    let day = C::NAME[3..].parse::<i32>().unwrap();
-    let url = format!("https://adventofcode.com/{}/day/{}/answer", YEAR, day);
+    /// Downloads the latest description for this day into its README
+    pub fn update_readme(&self, client: &AocClient) -> Result<Description, Error> {
+        run::update_readme(client, self.year, self.name)
+    }
+}
+
+impl std::fmt::Display for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.name)
+    }
+}
 
-    ureq::post(&url)
-        .set("Cookie", &format!("session={session}"))
-        .send_form(&[
-            ("level", &format!("{level}")),
-            ("answer", &format!("{answer}")),
-        ])
-        .unwrap();
+/// Registers a challenge type as a [`Day`], followed by `visualize` or `generate` for challenges that implement
+/// [`Visualize`] or [`Generate`]
+///
+/// ```ignore
+/// static DAYS: &[aoc::Day] = &[aoc::day!(aoc2021_day01::Day01), aoc::day!(aoc2021_day11::Day11, visualize)];
+/// ```
+#[macro_export]
+macro_rules! day {
+    (@visualize $challenge:ty) => {
+        |input: &str, show: &mut dyn FnMut(String)| $crate::visualize::<$challenge>(input, show)
+    };
+    (@generate $challenge:ty) => {
+        <$challenge as $crate::Generate>::generate
+    };
+    ($challenge:ty $(, $extra:ident)*) => {
+        $crate::Day {
+            $($extra: Some($crate::day!(@$extra $challenge)),)*
+            ..$crate::Day {
+                name: <$challenge as $crate::Challenge>::NAME,
+                year: <$challenge as $crate::Challenge>::YEAR,
+                load: $crate::load::<$challenge>,
+                run: |client: &$crate::AocClient, input: &str, options: &$crate::RunOptions, lines: &mut Vec<String>| {
+                    $crate::run::<$challenge>(client, input, options, lines)
+                },
+                time: |input: &str| $crate::time::<$challenge>(input),
+                verify: |input: &str, expected: &$crate::Expected| $crate::verify::<$challenge>(input, expected),
+                check: |input: &str, level: usize, expected: Option<&str>| {
+                    $crate::check::<$challenge>(input, level, expected)
+                },
+                visualize: None,
+                generate: None,
+            }
+        }
+    };
 }
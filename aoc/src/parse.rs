@@ -0,0 +1,243 @@
+use std::fmt;
+
+use nom::error::ErrorKind;
+
+use crate::Parser;
+
+/// Why a challenge input could not be parsed, and where
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    /// The parser rejected the input
+    Invalid { location: Location, expected: ErrorKind },
+    /// The parser asked for more input than there is
+    Incomplete,
+    /// The parser succeeded but left non-whitespace input behind, or itself insisted on the end of the input too early
+    Trailing { location: Location },
+}
+
+/// A position within the input, with the line it falls on for display
+#[derive(Debug, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+}
+
+impl Location {
+    fn new(input: &str, offset: usize) -> Self {
+        let before = &input[..offset];
+        let start = before.rfind('\n').map_or(0, |i| i + 1);
+        let end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: input[start..offset].chars().count() + 1,
+            source_line: input[start..end].trim_end_matches('\r').to_owned(),
+        }
+    }
+
+    fn of(input: &str, rest: &str) -> Self {
+        Self::new(input, input.len() - rest.len())
+    }
+}
+
+/// Parse a challenge, reporting anything left over as an error
+pub fn parse<'i, P: Parser<'i>>(input: &'i str) -> Result<P, ParseError> {
+    let _span = tracing::info_span!("parse").entered();
+    match P::parse(input) {
+        Ok((rest, challenge)) => {
+            let trailing = rest.trim_start();
+            if trailing.is_empty() {
+                Ok(challenge)
+            } else {
+                let location = Location::of(input, trailing);
+                Err(ParseError::Trailing { location })
+            }
+        }
+        // `eof` and `all_consuming` fail with the same error as `take` running out, but mean there's input left over
+        Err(nom::Err::Error(e) | nom::Err::Failure(e))
+            if e.code == ErrorKind::Eof
+                && !e.input.is_empty()
+                && !runs_out::<P>(&input[..input.len() - e.input.len()]) =>
+        {
+            Err(ParseError::Trailing {
+                location: Location::of(input, e.input),
+            })
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::Invalid {
+            location: Location::of(input, e.input),
+            expected: e.code,
+        }),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::Incomplete),
+    }
+}
+
+/// Whether the parser fails at the very end of the input for want of more of it
+fn runs_out<'i, P: Parser<'i>>(input: &'i str) -> bool {
+    matches!(
+        P::parse(input),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) if e.code == ErrorKind::Eof && e.input.is_empty()
+    )
+}
+
+fn expected(kind: ErrorKind) -> String {
+    let expected = match kind {
+        ErrorKind::Tag => "a literal tag",
+        ErrorKind::Char => "a specific character",
+        ErrorKind::OneOf => "one of a set of characters",
+        ErrorKind::Digit => "a number",
+        ErrorKind::IsA => "a run of specific characters",
+        ErrorKind::CrLf => "a line ending",
+        ErrorKind::Alt => "one of several alternatives",
+        ErrorKind::Many1 | ErrorKind::SeparatedList => "at least one item",
+        ErrorKind::MapRes => "a valid value",
+        ErrorKind::Eof => "more input",
+        kind => return kind.description().to_lowercase(),
+    };
+    expected.to_owned()
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (location, message) = match self {
+            ParseError::Invalid {
+                location,
+                expected: kind,
+            } => (location, format!("expected {}", expected(*kind))),
+            ParseError::Trailing { location } => (location, "unexpected input after the end".to_owned()),
+            ParseError::Incomplete => return write!(f, "parse error: input ended early"),
+        };
+        let Location {
+            line,
+            column,
+            source_line,
+        } = location;
+        let gutter = " ".repeat(line.to_string().len());
+
+        writeln!(f, "parse error at line {line}, column {column}: {message}")?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {source_line}")?;
+        write!(f, "{gutter} | {:>column$}", "^")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        bytes::complete::{tag, take},
+        character::complete::line_ending,
+        combinator::eof,
+        error::ErrorKind,
+        sequence::separated_pair,
+        IResult, Parser as _,
+    };
+    use parsers::{number, ParserExt};
+
+    use super::{parse, Location, ParseError};
+    use crate::{Challenge, Parser};
+
+    #[derive(Debug)]
+    struct Pairs(Vec<(u32, u32)>);
+
+    impl<'i> Parser<'i> for Pairs {
+        fn parse(input: &'i str) -> IResult<&'i str, Self> {
+            separated_pair(number, tag(","), number)
+                .separated_list1(line_ending)
+                .skip(tag("\nend"))
+                .map(Pairs)
+                .parse(input)
+        }
+    }
+
+    impl Challenge for Pairs {
+        const NAME: &'static str = "day00";
+        const YEAR: usize = 2021;
+
+        type Output1 = usize;
+        type Output2 = usize;
+
+        fn part_one(&self) -> usize {
+            self.0.len()
+        }
+
+        fn part_two(&self) -> usize {
+            0
+        }
+    }
+
+    /// Exactly three characters, to tell running out of input from having some left over
+    #[derive(Debug)]
+    struct Three;
+
+    impl<'i> Parser<'i> for Three {
+        fn parse(input: &'i str) -> IResult<&'i str, Self> {
+            take(3usize).skip(eof).map(|_| Three).parse(input)
+        }
+    }
+
+    impl Challenge for Three {
+        const NAME: &'static str = "day00";
+        const YEAR: usize = 2021;
+
+        type Output1 = usize;
+        type Output2 = usize;
+
+        fn part_one(&self) -> usize {
+            0
+        }
+
+        fn part_two(&self) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn valid() {
+        let pairs = parse::<Pairs>("1,2\n3,4\nend\n\n").unwrap();
+        assert_eq!(pairs.0, [(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn invalid() {
+        let error = parse::<Pairs>("1,2\n3,4\nand\n").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::Invalid {
+                location: Location {
+                    line: 2,
+                    column: 4,
+                    source_line: "3,4".to_owned()
+                },
+                expected: ErrorKind::Tag,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 2, column 4: expected a literal tag\n  |\n2 | 3,4\n  |    ^"
+        );
+    }
+
+    #[test]
+    fn trailing() {
+        let error = parse::<Pairs>("1,2\nend\n  12,x\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 3, column 3: unexpected input after the end\n  |\n3 |   12,x\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn end_of_input() {
+        let error = parse::<Three>("ab").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 1, column 1: expected more input\n  |\n1 | ab\n  | ^"
+        );
+
+        let error = parse::<Three>("abcd").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 1, column 4: unexpected input after the end\n  |\n1 | abcd\n  |    ^"
+        );
+    }
+}
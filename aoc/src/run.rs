@@ -0,0 +1,295 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    parse, Answer, Answers, AocClient, Challenge, ChallengeError, Description, Error, Expected, IntoAnswer, Ledger,
+    Parser, SubmitOutcome,
+};
+
+/// Where a challenge keeps its input, README and ledger: `challenges/<YEAR>/<NAME>`
+pub fn challenge_dir(year: usize, name: &str) -> PathBuf {
+    Path::new("challenges").join(year.to_string()).join(name)
+}
+
+/// Reads the input for a challenge, downloading it first if it isn't there yet
+pub fn load<C: Challenge>(client: &AocClient) -> Result<String, Error> {
+    let _span = tracing::info_span!("load").entered();
+    let file = challenge_dir(C::YEAR, C::NAME).join("input.txt");
+    let day = C::NAME[3..].parse().unwrap();
+    cached_input(client, C::YEAR, day, &file)
+}
+
+fn cached_input(client: &AocClient, year: usize, day: u32, file: &Path) -> Result<String, Error> {
+    match fs::read_to_string(file) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            eprintln!("Downloading the input to {}", file.display());
+            let input = client.input(year, day)?;
+            fs::write(file, &input).map_err(|source| Error::Io {
+                path: file.to_owned(),
+                source,
+            })?;
+            Ok(input)
+        }
+        input => input.map_err(|source| Error::Io {
+            path: file.to_owned(),
+            source,
+        }),
+    }
+}
+
+/// Reads the description of a challenge from its README, downloading it first if it isn't there yet
+fn readme(client: &AocClient, year: usize, name: &str) -> Result<Description, Error> {
+    let file = challenge_dir(year, name).join("README.md");
+    match fs::read_to_string(&file) {
+        Ok(readme) => Ok(Description::from_markdown(&readme)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => update_readme(client, year, name),
+        Err(source) => Err(Error::Io { path: file, source }),
+    }
+}
+
+pub(crate) fn update_readme(client: &AocClient, year: usize, name: &str) -> Result<Description, Error> {
+    let file = challenge_dir(year, name).join("README.md");
+    let description = client.description(year, name[3..].parse().unwrap())?;
+    fs::write(&file, description.to_markdown()).map_err(|source| Error::Io { path: file, source })?;
+    Ok(description)
+}
+
+/// Which parts of a challenge to run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Parts {
+    /// Whichever part is unsolved, going by the README
+    Auto,
+    One,
+    Two,
+    Both,
+}
+
+/// What to do with the answers once they're computed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    /// Submit unless the ledger already knows the verdict
+    Submit,
+    /// Submit even if the ledger says not to
+    Force,
+    /// Only print the answers
+    DryRun,
+    /// Compare against the accepted answers in the ledger or answers.toml without submitting
+    Check,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RunOptions {
+    pub parts: Parts,
+    pub mode: Mode,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            parts: Parts::Auto,
+            mode: Mode::Submit,
+        }
+    }
+}
+
+impl FromStr for Parts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Parts::Auto),
+            "1" | "one" => Ok(Parts::One),
+            "2" | "two" => Ok(Parts::Two),
+            "both" => Ok(Parts::Both),
+            _ => Err(format!("expected 1, 2, both or auto, not {s:?}")),
+        }
+    }
+}
+
+/// Solves the chosen parts and does what the mode says with each answer, adding a line to `lines` for each step.
+/// Returns whether anything went wrong
+pub fn run<'i, P: Parser<'i>>(
+    client: &AocClient,
+    input: &'i str,
+    options: &RunOptions,
+    lines: &mut Vec<String>,
+) -> bool {
+    let levels: &[usize] = match options.parts {
+        Parts::One => &[1],
+        Parts::Two => &[2],
+        Parts::Both => &[1, 2],
+        Parts::Auto => match readme(client, P::YEAR, P::NAME) {
+            Ok(description) if description.has_part_two() => &[2],
+            Ok(_) => &[1],
+            Err(e) => {
+                lines.push(format!("Could not read the description: {e}"));
+                return true;
+            }
+        },
+    };
+
+    let path = challenge_dir(P::YEAR, P::NAME).join("ledger.toml");
+    let mut ledger = match Ledger::load(&path) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            lines.push(e.to_string());
+            return true;
+        }
+    };
+
+    // days solved before they had a ledger only have their answers in answers.toml
+    let expected = match options.mode {
+        Mode::Check => Answers::load(Path::new("answers.toml")).map_or_else(
+            |e| {
+                lines.push(e.to_string());
+                Expected::default()
+            },
+            |answers| answers.get(P::YEAR, P::NAME),
+        ),
+        _ => Expected::default(),
+    };
+
+    let challenge = match parse::<P>(input) {
+        Ok(challenge) => challenge,
+        Err(e) => {
+            lines.push(e.to_string());
+            return true;
+        }
+    };
+
+    let mut failed = false;
+    for &level in levels {
+        let part = PART_NAMES[level - 1];
+        let answer = match solve(&challenge, level) {
+            Ok(answer) => answer,
+            Err(e) => {
+                failed |= e != ChallengeError::Unimplemented;
+                lines.push(format!("Part {part}: {e}"));
+                continue;
+            }
+        };
+        lines.push(format!("Answer to part {part}: {answer}"));
+
+        match options.mode {
+            Mode::DryRun => {}
+            Mode::Check => match ledger.correct(level).or(expected.part(level)) {
+                Some(correct) if correct == answer.to_string() => lines.push("Matches the accepted answer".to_owned()),
+                Some(correct) => {
+                    failed = true;
+                    lines.push(format!("Does not match the accepted answer {correct}"));
+                }
+                None => lines.push("No accepted answer to check against".to_owned()),
+            },
+            Mode::Submit | Mode::Force => {
+                let force = options.mode == Mode::Force;
+                failed |= submit::<P>(client, &mut ledger, &path, level, &answer, force, lines);
+            }
+        }
+    }
+    failed
+}
+
+const PART_NAMES: [&str; 2] = ["one", "two"];
+
+/// Solves one part, in a span named after it
+pub(crate) fn solve<C: Challenge>(challenge: &C, level: usize) -> Result<Answer, ChallengeError> {
+    match level {
+        1 => tracing::info_span!("part_one").in_scope(|| challenge.part_one().into_answer()),
+        _ => tracing::info_span!("part_two").in_scope(|| challenge.part_two().into_answer()),
+    }
+}
+
+/// Submits an answer unless the ledger already knows it's wrong, returning whether anything went wrong
+fn submit<C: Challenge>(
+    client: &AocClient,
+    ledger: &mut Ledger,
+    path: &Path,
+    level: usize,
+    answer: &Answer,
+    force: bool,
+    lines: &mut Vec<String>,
+) -> bool {
+    let _span = tracing::info_span!("submit", level).entered();
+    if let Err(refusal) = ledger.check(level, answer) {
+        if !force {
+            lines.push(format!("Not submitting: {refusal}"));
+            return false;
+        }
+        lines.push(format!("Submitting anyway: {refusal}"));
+    }
+
+    let day = C::NAME[3..].parse().unwrap();
+    let outcome = match client.submit(C::YEAR, day, level, answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            lines.push(format!("Could not submit: {e}"));
+            return true;
+        }
+    };
+    lines.push(format!("Submitted: {outcome}"));
+    ledger.record(level, answer, &outcome);
+    if let Err(e) = ledger.save(path) {
+        lines.push(format!("Could not update the ledger: {e}"));
+        return true;
+    }
+    if level == 1 && outcome == SubmitOutcome::Correct {
+        match update_readme(client, C::YEAR, C::NAME) {
+            Ok(_) => lines.push("Part two has been added to the README".to_owned()),
+            Err(e) => {
+                lines.push(format!("Could not update the README: {e}"));
+                return true;
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{cached_input, Parts};
+    use crate::{test_server::TestServer, AocClient, Error};
+
+    #[test]
+    fn downloads_missing_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("input.txt");
+
+        let server = TestServer::serve(vec![(200, "199\n200\n208\n")]);
+        let client = AocClient::new(Some("abc123".to_owned())).base_url(&server.url);
+
+        assert_eq!(cached_input(&client, 2020, 1, &file).unwrap(), "199\n200\n208\n");
+        assert_eq!(server.request().path, "/2020/day/1/input");
+        assert_eq!(fs::read_to_string(&file).unwrap(), "199\n200\n208\n");
+
+        // the server has gone away, so this can only come from the cache
+        assert_eq!(cached_input(&client, 2020, 1, &file).unwrap(), "199\n200\n208\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_session() {
+        let file = std::env::temp_dir().join("aoc-missing-session").join("input.txt");
+        let client = AocClient::new(None);
+
+        assert!(matches!(
+            cached_input(&client, 2021, 1, &file),
+            Err(Error::MissingSession)
+        ));
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!("1".parse(), Ok(Parts::One));
+        assert_eq!("two".parse(), Ok(Parts::Two));
+        assert_eq!("both".parse(), Ok(Parts::Both));
+        assert_eq!("auto".parse(), Ok(Parts::Auto));
+        assert!("3".parse::<Parts>().is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use std::{fmt, time::Duration};
+
+/// What Advent of Code said about a submitted answer
+#[derive(Debug, PartialEq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// Submitted too soon after a previous answer
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part was already solved, or part one hasn't been yet
+    WrongLevel,
+    /// A response we don't recognise, as the text of its main article
+    Unknown(String),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl SubmitOutcome {
+    /// Interprets the HTML page returned after posting an answer
+    pub fn from_html(html: &str) -> Self {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            SubmitOutcome::Incorrect { hint }
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(wait, _)| parse_wait(wait));
+            SubmitOutcome::RateLimited { wait }
+        } else if text.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::WrongLevel
+        } else {
+            SubmitOutcome::Unknown(text)
+        }
+    }
+}
+
+/// The text content of the page's `<article>`, or the whole page if there isn't one
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::with_capacity(article.len());
+    let mut rest = article;
+    while let Some((before, after)) = rest.split_once('<') {
+        text.push_str(before);
+        rest = after.split_once('>').map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+
+    text.replace("&apos;", "'")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+}
+
+/// Parses waits of the form `4m 12s`
+fn parse_wait(wait: &str) -> Option<Duration> {
+    wait.split_whitespace().try_fold(Duration::ZERO, |total, part| {
+        let unit = match part.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        let n: u64 = part[..part.len() - 1].parse().ok()?;
+        Some(total + Duration::from_secs(n * unit))
+    })
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "correct!"),
+            SubmitOutcome::Incorrect { hint: None } => write!(f, "incorrect"),
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => write!(f, "incorrect, too high"),
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => write!(f, "incorrect, too low"),
+            SubmitOutcome::RateLimited { wait: None } => write!(f, "rate limited, try again later"),
+            SubmitOutcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, try again in {}s", wait.as_secs())
+            }
+            SubmitOutcome::WrongLevel => write!(f, "wrong level, is this part already solved?"),
+            SubmitOutcome::Unknown(text) => write!(f, "unrecognised response: {}", text.trim()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Hint, SubmitOutcome};
+    use crate::{test_server::TestServer, Answer, AocClient, Error};
+
+    fn client(url: &str) -> AocClient {
+        AocClient::new(Some("abc123".to_owned())).base_url(url)
+    }
+
+    fn submit(status: u16, page: &'static str) -> Result<SubmitOutcome, Error> {
+        let server = TestServer::serve(vec![(status, page)]);
+        client(&server.url).submit(2021, 7, 1, &Answer::from(352331_usize))
+    }
+
+    #[test]
+    fn correct() {
+        let outcome = submit(200, include_str!("../fixtures/submit/correct.html"));
+        assert_eq!(outcome.unwrap(), SubmitOutcome::Correct);
+    }
+
+    #[test]
+    fn incorrect() {
+        let outcome = submit(200, include_str!("../fixtures/submit/incorrect.html"));
+        assert_eq!(outcome.unwrap(), SubmitOutcome::Incorrect { hint: None });
+
+        let outcome = submit(200, include_str!("../fixtures/submit/too_high.html"));
+        let hint = Some(Hint::TooHigh);
+        assert_eq!(outcome.unwrap(), SubmitOutcome::Incorrect { hint });
+
+        let outcome = submit(200, include_str!("../fixtures/submit/too_low.html"));
+        let hint = Some(Hint::TooLow);
+        assert_eq!(outcome.unwrap(), SubmitOutcome::Incorrect { hint });
+    }
+
+    #[test]
+    fn rate_limited() {
+        let outcome = submit(200, include_str!("../fixtures/submit/rate_limited.html"));
+        let wait = Some(Duration::from_secs(4 * 60 + 12));
+        assert_eq!(outcome.unwrap(), SubmitOutcome::RateLimited { wait });
+    }
+
+    #[test]
+    fn wrong_level() {
+        let outcome = submit(200, include_str!("../fixtures/submit/wrong_level.html"));
+        assert_eq!(outcome.unwrap(), SubmitOutcome::WrongLevel);
+    }
+
+    #[test]
+    fn unknown() {
+        let outcome = submit(200, "<html><main><article><p>Something new</p></article></main></html>");
+        assert_eq!(outcome.unwrap(), SubmitOutcome::Unknown("Something new".to_owned()));
+    }
+
+    #[test]
+    fn request() {
+        let server = TestServer::serve(vec![(200, include_str!("../fixtures/submit/correct.html"))]);
+        client(&server.url)
+            .submit(2021, 7, 2, &Answer::from("EFJKZLBL"))
+            .unwrap();
+
+        let request = server.request();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/2021/day/7/answer");
+        assert_eq!(request.header("cookie"), Some("session=abc123"));
+        assert_eq!(request.body, "level=2&answer=EFJKZLBL");
+    }
+
+    #[test]
+    fn status_error() {
+        let error = submit(500, "oops").unwrap_err();
+        assert!(matches!(error, Error::Status { status: 500, .. }), "{error}");
+    }
+
+    #[test]
+    fn network_error() {
+        let error = client("http://127.0.0.1:1")
+            .submit(2021, 7, 1, &Answer::from(1_usize))
+            .unwrap_err();
+        assert!(matches!(error, Error::Network { .. }), "{error}");
+    }
+}
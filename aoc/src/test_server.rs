@@ -0,0 +1,90 @@
+//! A tiny HTTP stand-in for adventofcode.com that serves canned responses in tests
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::mpsc::{channel, Receiver},
+    thread,
+};
+
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct TestServer {
+    pub url: String,
+    requests: Receiver<Request>,
+}
+
+impl TestServer {
+    /// Answers one request per response, in order, then shuts down
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, requests) = channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut parts = line.split_whitespace();
+                let method = parts.next().unwrap().to_owned();
+                let path = parts.next().unwrap().to_owned();
+
+                let mut headers = vec![];
+                loop {
+                    line.clear();
+                    reader.read_line(&mut line).unwrap();
+                    match line.trim_end().split_once(": ") {
+                        Some((key, value)) => headers.push((key.to_owned(), value.to_owned())),
+                        None => break,
+                    }
+                }
+                let request = Request {
+                    method,
+                    path,
+                    headers,
+                    body: String::new(),
+                };
+
+                let length = request.header("content-length").map_or(0, |l| l.parse().unwrap());
+                let mut payload = vec![0; length];
+                reader.read_exact(&mut payload).unwrap();
+                let request = Request {
+                    body: String::from_utf8(payload).unwrap(),
+                    ..request
+                };
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Canned\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                tx.send(request).unwrap();
+            }
+        });
+
+        TestServer { url, requests }
+    }
+
+    /// The next request the server answered
+    pub fn request(&self) -> Request {
+        self.requests.recv().unwrap()
+    }
+}
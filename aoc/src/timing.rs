@@ -0,0 +1,79 @@
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::{allocations::measure, parse, run::solve, Allocations, ChallengeError, ParseError, Parser};
+
+/// How long each phase of a challenge took on one input
+#[derive(Debug, Clone, Serialize)]
+pub struct Timings {
+    pub name: &'static str,
+    pub year: usize,
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse: Duration,
+    #[serde(rename = "part_one_ns", serialize_with = "nanos")]
+    pub part_one: Duration,
+    #[serde(rename = "part_two_ns", serialize_with = "nanos")]
+    pub part_two: Duration,
+    /// Why each part failed, if it did. Its time is then how long it took to give up
+    #[serde(serialize_with = "error")]
+    pub part_one_error: Option<ChallengeError>,
+    #[serde(serialize_with = "error")]
+    pub part_two_error: Option<ChallengeError>,
+    /// Only measured when the runner uses the counting allocator
+    pub allocations: Option<PhaseAllocations>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct PhaseAllocations {
+    pub parse: Allocations,
+    pub part_one: Allocations,
+    pub part_two: Allocations,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+fn nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+fn error<S: serde::Serializer>(error: &Option<ChallengeError>, serializer: S) -> Result<S::Ok, S::Error> {
+    match error {
+        Some(e) => serializer.serialize_some(&e.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let t = f();
+    (t, start.elapsed())
+}
+
+/// Times parsing the input and solving each part once, counting allocations too if the allocator allows
+pub fn time<'i, P: Parser<'i>>(input: &'i str) -> Result<Timings, ParseError> {
+    let ((challenge, parsing), parse_allocations) = measure(|| timed(|| parse::<P>(input)));
+    let challenge = challenge?;
+    let ((one, part_one), part_one_allocations) = measure(|| timed(|| solve(&challenge, 1)));
+    let ((two, part_two), part_two_allocations) = measure(|| timed(|| solve(&challenge, 2)));
+
+    let allocations = parse_allocations.zip(part_one_allocations).zip(part_two_allocations);
+    Ok(Timings {
+        name: P::NAME,
+        year: P::YEAR,
+        parse: parsing,
+        part_one,
+        part_two,
+        part_one_error: one.err(),
+        part_two_error: two.err(),
+        allocations: allocations.map(|((parse, part_one), part_two)| PhaseAllocations {
+            parse,
+            part_one,
+            part_two,
+        }),
+    })
+}
@@ -0,0 +1,81 @@
+use std::fmt::Debug;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{parse, Challenge, Parser};
+
+/// A challenge that can be written back out as puzzle input, the inverse of its [`Parser`](crate::Parser)
+///
+/// The output is in the puzzle's canonical format, ending in a newline like a downloaded input,
+/// so generated or modified challenges can be saved and parsing it gives back an equal challenge.
+pub trait Unparse: Challenge {
+    fn unparse(&self) -> String;
+}
+
+/// Checks that 100 challenges made up by `generate` parse back to themselves once unparsed, for a day's tests
+///
+/// The unparsed inputs are leaked, so that challenges which borrow from their input can be compared too.
+pub fn assert_roundtrip<P>(mut generate: impl FnMut(&mut StdRng) -> P)
+where
+    P: Parser<'static> + Unparse + PartialEq + Debug,
+{
+    let mut rng = StdRng::seed_from_u64(23);
+    for _ in 0..100 {
+        let challenge = generate(&mut rng);
+        let input = challenge.unparse().leak();
+        assert_eq!(parse::<P>(input), Ok(challenge), "unparsed as:\n{input}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{character::complete::line_ending, IResult, Parser as _};
+    use parsers::{number, ParserExt};
+    use rand::Rng;
+
+    use super::{assert_roundtrip, Unparse};
+    use crate::{Challenge, Parser};
+
+    #[derive(Debug, PartialEq)]
+    struct Depths(Vec<u32>);
+
+    impl<'i> Parser<'i> for Depths {
+        fn parse(input: &'i str) -> IResult<&'i str, Self> {
+            number::<u32>.separated_list1(line_ending).map(Depths).parse(input)
+        }
+    }
+
+    impl Challenge for Depths {
+        const NAME: &'static str = "day01";
+        const YEAR: usize = 2021;
+
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn part_one(&self) -> u32 {
+            self.0.iter().sum()
+        }
+
+        fn part_two(&self) -> u32 {
+            *self.0.iter().max().unwrap()
+        }
+    }
+
+    impl Unparse for Depths {
+        fn unparse(&self) -> String {
+            self.0.iter().map(|depth| format!("{depth}\n")).collect()
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        assert_roundtrip(|rng| Depths((0..rng.gen_range(1..20)).map(|_| rng.gen()).collect()));
+    }
+
+    #[test]
+    #[should_panic(expected = "unparsed as")]
+    fn mismatch() {
+        // parsing can't give back an empty list
+        assert_roundtrip(|_| Depths(vec![]));
+    }
+}
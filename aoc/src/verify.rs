@@ -0,0 +1,172 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::{parse, run::solve, Answer, ChallengeError, Error, ParseError, Parser};
+
+/// The checked-in table of accepted answers, keyed by year and then challenge name
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Expected>>);
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct Expected {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let answers = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+        toml::from_str(&answers).map_err(|e| Error::Invalid {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })
+    }
+
+    pub fn get(&self, year: usize, name: &str) -> Expected {
+        let day = self.0.get(&year.to_string()).and_then(|days| days.get(name));
+        day.cloned().unwrap_or_default()
+    }
+}
+
+impl Expected {
+    /// The accepted answer to one part, if there is one
+    pub fn part(&self, level: usize) -> Option<&str> {
+        match level {
+            1 => self.part_one.as_deref(),
+            _ => self.part_two.as_deref(),
+        }
+    }
+}
+
+/// How a computed answer compares to the recorded one
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Answer,
+    },
+    Unrecorded(Answer),
+    /// The part returned an error instead of an answer
+    Unsolved(ChallengeError),
+}
+
+impl Check {
+    fn new(expected: Option<&str>, actual: Result<Answer, ChallengeError>) -> Self {
+        let actual = match actual {
+            Ok(actual) => actual,
+            Err(e) => return Check::Unsolved(e),
+        };
+        match expected {
+            Some(expected) if expected == actual.to_string() => Check::Pass,
+            Some(expected) => Check::Fail {
+                expected: expected.to_owned(),
+                actual,
+            },
+            None => Check::Unrecorded(actual),
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Pass => write!(f, "ok"),
+            Check::Fail { expected, actual } => write!(f, "FAILED, expected {expected} but got {actual}"),
+            Check::Unrecorded(actual) => write!(f, "no recorded answer, got {actual}"),
+            Check::Unsolved(e) => e.fmt(f),
+        }
+    }
+}
+
+/// Solves both parts and compares them against the recorded answers
+pub fn verify<'i, P: Parser<'i>>(input: &'i str, expected: &Expected) -> Result<[Check; 2], ParseError> {
+    let challenge = parse::<P>(input)?;
+    let part_one = solve(&challenge, 1);
+    let part_two = solve(&challenge, 2);
+
+    Ok([
+        Check::new(expected.part_one.as_deref(), part_one),
+        Check::new(expected.part_two.as_deref(), part_two),
+    ])
+}
+
+/// Solves one part and compares it against an expected answer, if there is one
+pub fn check<'i, P: Parser<'i>>(input: &'i str, level: usize, expected: Option<&str>) -> Result<Check, ParseError> {
+    let challenge = parse::<P>(input)?;
+    Ok(Check::new(expected, solve(&challenge, level)))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{character::complete::line_ending, IResult, Parser as _};
+    use parsers::{number, ParserExt};
+
+    use super::{check, verify, Answers, Check};
+    use crate::{Answer, Challenge, ChallengeError, Parser};
+
+    struct Depths(Vec<u32>);
+
+    impl<'i> Parser<'i> for Depths {
+        fn parse(input: &'i str) -> IResult<&'i str, Self> {
+            number::<u32>.separated_list1(line_ending).map(Depths).parse(input)
+        }
+    }
+
+    impl Challenge for Depths {
+        const NAME: &'static str = "day01";
+        const YEAR: usize = 2021;
+
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn part_one(&self) -> u32 {
+            self.0.iter().sum()
+        }
+
+        fn part_two(&self) -> u32 {
+            *self.0.iter().max().unwrap()
+        }
+    }
+
+    #[test]
+    fn checks() {
+        let answers: Answers = toml::from_str(
+            r#"
+            [2021.day01]
+            part_one = "6"
+            part_two = "4"
+            [2021.day02]
+            part_one = "1"
+            "#,
+        )
+        .unwrap();
+
+        let [part_one, part_two] = verify::<Depths>("1\n2\n3\n", &answers.get(2021, "day01")).unwrap();
+        assert_eq!(part_one, Check::Pass);
+        assert_eq!(
+            part_two,
+            Check::Fail {
+                expected: "4".to_owned(),
+                actual: Answer::from(3_u32)
+            }
+        );
+
+        let [part_one, part_two] = verify::<Depths>("1\n2\n3\n", &answers.get(2020, "day01")).unwrap();
+        assert_eq!(part_one, Check::Unrecorded(Answer::from(6_u32)));
+        assert_eq!(part_two, Check::Unrecorded(Answer::from(3_u32)));
+        assert_eq!(answers.get(2021, "day02").part(1), Some("1"));
+        assert_eq!(answers.get(2021, "day02").part(2), None);
+
+        assert_eq!(check::<Depths>("1\n2\n3\n", 2, Some("3")).unwrap(), Check::Pass);
+        assert!(check::<Depths>("1\n2\nthree\n", 2, Some("3")).is_err());
+
+        let unsolved = Check::new(Some("4"), Err(ChallengeError::NoSolution));
+        assert_eq!(unsolved, Check::Unsolved(ChallengeError::NoSolution));
+        assert_eq!(unsolved.to_string(), "no solution");
+    }
+}
@@ -0,0 +1,90 @@
+use thiserror::Error;
+
+use crate::{parse, Challenge, ChallengeError, ParseError, Parser};
+
+/// A challenge whose intermediate states are worth watching, played by `aoc run --visualize`
+///
+/// Register it with `aoc::day!(Day11, visualize)` so the runner can find it.
+pub trait Visualize: Challenge {
+    /// Each frame is a complete picture of the state, ready to print to the terminal. Fails the way the parts do
+    /// when there's nothing to show
+    fn frames(&self) -> Result<impl Iterator<Item = String> + '_, ChallengeError>;
+}
+
+/// Why [`visualize`] could not play a challenge
+#[derive(Debug, PartialEq, Error)]
+pub enum VisualizeError {
+    #[error("{0}")]
+    Parse(ParseError),
+    #[error("{0}")]
+    Challenge(ChallengeError),
+}
+
+/// [`visualize`] for one challenge, as stored in a [`Day`](crate::Day)
+pub type Visualizer = fn(&str, &mut dyn FnMut(String)) -> Result<(), VisualizeError>;
+
+/// Parses the input and hands each frame to `show` as it's produced
+pub fn visualize<'i, P>(input: &'i str, show: &mut dyn FnMut(String)) -> Result<(), VisualizeError>
+where
+    P: Parser<'i> + Visualize,
+{
+    let challenge = parse::<P>(input).map_err(VisualizeError::Parse)?;
+    challenge.frames().map_err(VisualizeError::Challenge)?.for_each(show);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{character::complete::digit1, IResult, Parser as _};
+
+    use super::{visualize, Visualize, VisualizeError};
+    use crate::{Challenge, ChallengeError, Parser};
+
+    struct Countdown(u32);
+
+    impl<'i> Parser<'i> for Countdown {
+        fn parse(input: &'i str) -> IResult<&'i str, Self> {
+            digit1.map(|n: &str| Countdown(n.parse().unwrap())).parse(input)
+        }
+    }
+
+    impl Challenge for Countdown {
+        const NAME: &'static str = "day01";
+        const YEAR: usize = 2021;
+
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn part_one(&self) -> u32 {
+            self.0
+        }
+
+        fn part_two(&self) -> u32 {
+            0
+        }
+    }
+
+    impl Visualize for Countdown {
+        fn frames(&self) -> Result<impl Iterator<Item = String> + '_, ChallengeError> {
+            if self.0 > 9 {
+                return Err(ChallengeError::InvalidInput("too long to count down".to_owned()));
+            }
+            Ok((0..=self.0).rev().map(|n| n.to_string()))
+        }
+    }
+
+    #[test]
+    fn frames() {
+        let mut frames = vec![];
+        visualize::<Countdown>("3\n", &mut |frame| frames.push(frame)).unwrap();
+        assert_eq!(frames, ["3", "2", "1", "0"]);
+
+        assert!(matches!(
+            visualize::<Countdown>("three", &mut |_| {}),
+            Err(VisualizeError::Parse(_))
+        ));
+
+        let error = visualize::<Countdown>("10\n", &mut |_| panic!("no frames should be shown")).unwrap_err();
+        assert_eq!(error.to_string(), "invalid input: too long to count down");
+    }
+}
@@ -0,0 +1,45 @@
+use aoc::{
+    rand::{rngs::StdRng, SeedableRng},
+    Challenge, Generate, Parser,
+};
+use aoc2021_day01::Day01;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+pub fn day01(c: &mut Criterion) {
+    let mut group = c.benchmark_group(Day01::NAME);
+
+    let input = include_str!("../input.txt");
+    let challenge = Day01::parse(input).unwrap().1;
+
+    group.bench_function("parse", |b| b.iter(|| Day01::parse(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| challenge.part_one()));
+    group.bench_function("part2", |b| b.iter(|| challenge.part_two()));
+
+    group.finish();
+}
+
+pub fn day01_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("{}/scaling", Day01::NAME));
+    let mut rng = StdRng::seed_from_u64(1);
+
+    // up to ten times the real input's 2000 depths
+    for size in [20, 200, 2000, 20000] {
+        let input = Day01::generate(&mut rng, size);
+        let challenge = Day01::parse(&input).unwrap().1;
+
+        group.bench_with_input(BenchmarkId::new("parse", size), &input, |b, input| {
+            b.iter(|| Day01::parse(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part1", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_one())
+        });
+        group.bench_with_input(BenchmarkId::new("part2", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_two())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day01, day01_scaling);
+criterion_main!(benches);
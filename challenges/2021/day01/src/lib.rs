@@ -0,0 +1,96 @@
+use aoc::{
+    rand::{Rng, RngCore},
+    Challenge, Generate, Parser as ChallengeParser, Unparse,
+};
+use nom::{character::complete::line_ending, IResult, Parser};
+use parsers::{number, ParserExt};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Day01(Vec<i32>);
+
+impl<'i> ChallengeParser<'i> for Day01 {
+    fn parse(input: &'i str) -> IResult<&'i str, Self> {
+        number::<i32>.separated_list1(line_ending).map(Self).parse(input)
+    }
+}
+
+impl Unparse for Day01 {
+    fn unparse(&self) -> String {
+        self.0.iter().map(|depth| format!("{depth}\n")).collect()
+    }
+}
+
+impl Challenge for Day01 {
+    const NAME: &'static str = "day01";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn part_one(&self) -> usize {
+        self.0.array_windows().filter(|[a, b]| b > a).count()
+    }
+
+    fn part_two(&self) -> usize {
+        self.0.array_windows().filter(|[a, _, _, d]| d > a).count()
+    }
+}
+
+impl Generate for Day01 {
+    /// `size` depth measurements, mostly getting deeper
+    fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+        let mut depth: i32 = rng.gen_range(100..200);
+        (0..size)
+            .map(|_| {
+                depth = (depth + rng.gen_range(-10..=20)).max(0);
+                format!("{depth}\n")
+            })
+            .collect()
+    }
+}
+
+aoc::examples!(Day01);
+
+#[cfg(test)]
+mod tests {
+    use aoc::{
+        rand::{rngs::StdRng, Rng, SeedableRng},
+        Challenge, Generate, Parser,
+    };
+
+    use crate::Day01;
+
+    #[test]
+    fn part_one() {
+        let challenge = Day01(vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
+
+        assert_eq!(challenge.part_one(), 7)
+    }
+
+    #[test]
+    fn part_two() {
+        let challenge = Day01(vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
+
+        assert_eq!(challenge.part_two(), 5)
+    }
+
+    #[test]
+    fn generated() {
+        let input = Day01::generate(&mut StdRng::seed_from_u64(1), 1000);
+        let depths: Vec<i32> = input.lines().map(|line| line.parse().unwrap()).collect();
+        let increases = |gap| (gap..depths.len()).filter(|&i| depths[i] > depths[i - gap]).count();
+
+        let output = Day01::parse(&input).unwrap().1;
+        assert_eq!(output.part_one(), increases(1));
+        // windows of three that overlap share their middle two depths
+        assert_eq!(output.part_two(), increases(3));
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let size = rng.gen_range(1..50);
+            aoc::parse::<Day01>(&Day01::generate(rng, size)).unwrap()
+        });
+    }
+}
@@ -0,0 +1,45 @@
+use aoc::{
+    rand::{rngs::StdRng, SeedableRng},
+    Challenge, Generate, Parser,
+};
+use aoc2021_day02::Day02;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+pub fn day02(c: &mut Criterion) {
+    let mut group = c.benchmark_group(Day02::NAME);
+
+    let input = include_str!("../input.txt");
+    let challenge = Day02::parse(input).unwrap().1;
+
+    group.bench_function("parse", |b| b.iter(|| Day02::parse(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| challenge.part_one()));
+    group.bench_function("part2", |b| b.iter(|| challenge.part_two()));
+
+    group.finish();
+}
+
+pub fn day02_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("{}/scaling", Day02::NAME));
+    let mut rng = StdRng::seed_from_u64(2);
+
+    // up to ten times the real input's 1000 commands
+    for size in [10, 100, 1000, 10000] {
+        let input = Day02::generate(&mut rng, size);
+        let challenge = Day02::parse(&input).unwrap().1;
+
+        group.bench_with_input(BenchmarkId::new("parse", size), &input, |b, input| {
+            b.iter(|| Day02::parse(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part1", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_one())
+        });
+        group.bench_with_input(BenchmarkId::new("part2", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_two())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day02, day02_scaling);
+criterion_main!(benches);
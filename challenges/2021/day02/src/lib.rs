@@ -0,0 +1,187 @@
+use aoc::{
+    rand::{Rng, RngCore},
+    Challenge, Generate, Parser as ChallengeParser, Unparse,
+};
+use nom::{branch::alt, bytes::complete::tag, IResult, Parser};
+use parsers::*;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Dir {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+impl Dir {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            number.preceded_by(tag("forward ")).map(Dir::Forward),
+            number.preceded_by(tag("down ")).map(Dir::Down),
+            number.preceded_by(tag("up ")).map(Dir::Up),
+        ))(input)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Day02(pub Vec<Dir>);
+
+impl<'i> ChallengeParser<'i> for Day02 {
+    fn parse(input: &'i str) -> IResult<&'i str, Self> {
+        lines(Dir::parse).map(Self).parse(input)
+    }
+}
+
+impl Unparse for Day02 {
+    fn unparse(&self) -> String {
+        self.0
+            .iter()
+            .map(|dir| match dir {
+                Dir::Forward(n) => format!("forward {n}\n"),
+                Dir::Down(n) => format!("down {n}\n"),
+                Dir::Up(n) => format!("up {n}\n"),
+            })
+            .collect()
+    }
+}
+
+impl Challenge for Day02 {
+    const NAME: &'static str = "day02";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn part_one(&self) -> usize {
+        let (h, d) = self.0.iter().fold((0, 0), |(h, d), x| match x {
+            Dir::Forward(x) => (h + x, d),
+            Dir::Down(x) => (h, d + x),
+            Dir::Up(x) => (h, d - x),
+        });
+
+        (h * d) as usize
+    }
+
+    fn part_two(&self) -> usize {
+        let (h, d, _) = self.0.iter().fold((0, 0, 0), |(h, d, a), x| match x {
+            Dir::Forward(x) => (h + x, d + a * x, a),
+            Dir::Down(x) => (h, d, a + x),
+            Dir::Up(x) => (h, d, a - x),
+        });
+
+        (h * d) as usize
+    }
+}
+
+impl Generate for Day02 {
+    /// `size` commands, going up about as often as down so the aim stays small enough to multiply,
+    /// but never above the surface
+    fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+        let mut depth = 0;
+        (0..size)
+            .map(|_| {
+                let n = rng.gen_range(1..10);
+                let command = match rng.gen_range(0..3) {
+                    0 => "forward",
+                    1 if depth >= n => {
+                        depth -= n;
+                        "up"
+                    }
+                    _ => {
+                        depth += n;
+                        "down"
+                    }
+                };
+                format!("{command} {n}\n")
+            })
+            .collect()
+    }
+}
+
+aoc::examples!(Day02);
+
+#[cfg(test)]
+mod tests {
+    use aoc::{
+        rand::{rngs::StdRng, Rng, SeedableRng},
+        Challenge, Generate, Parser,
+    };
+
+    use crate::{Day02, Dir};
+
+    const INPUT: &str = "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn parse() {
+        let output = Day02::parse(INPUT).unwrap().1;
+
+        assert_eq!(
+            output.0,
+            vec![
+                Dir::Forward(5),
+                Dir::Down(5),
+                Dir::Forward(8),
+                Dir::Up(3),
+                Dir::Down(8),
+                Dir::Forward(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn part_one() {
+        let output = Day02::parse(INPUT).unwrap().1;
+
+        let x = output.part_one();
+        assert_eq!(x, 150);
+    }
+
+    #[test]
+    fn part_two() {
+        let output = Day02::parse(INPUT).unwrap().1;
+
+        let x = output.part_two();
+        assert_eq!(x, 900);
+    }
+
+    #[test]
+    fn generated() {
+        let input = Day02::generate(&mut StdRng::seed_from_u64(2), 1000);
+        let (mut position, mut depth, mut aim, mut aimed_depth) = (0, 0, 0, 0);
+        for line in input.lines() {
+            let (command, n) = line.split_once(' ').unwrap();
+            let n: usize = n.parse().unwrap();
+            match command {
+                "forward" => {
+                    position += n;
+                    aimed_depth += aim * n;
+                }
+                "down" => {
+                    depth += n;
+                    aim += n;
+                }
+                _ => {
+                    depth -= n;
+                    aim -= n;
+                }
+            }
+        }
+
+        let output = Day02::parse(&input).unwrap().1;
+        assert_eq!(output.part_one(), position * depth);
+        assert_eq!(output.part_two(), position * aimed_depth);
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let size = rng.gen_range(1..50);
+            aoc::parse::<Day02>(&Day02::generate(rng, size)).unwrap()
+        });
+    }
+}
@@ -1,6 +1,6 @@
 use aoc::{Challenge, Parser};
-use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
-use day03::Day03;
+use aoc2021_day03::Day03;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 pub fn day03(c: &mut Criterion) {
     let mut group = c.benchmark_group(Day03::NAME);
@@ -9,12 +9,8 @@ pub fn day03(c: &mut Criterion) {
     let challenge = Day03::parse(input).unwrap().1;
 
     group.bench_function("parse", |b| b.iter(|| Day03::parse(black_box(input))));
-    group.bench_function("part1", |b| {
-        b.iter_batched(|| challenge.clone(), Challenge::part_one, BatchSize::SmallInput)
-    });
-    group.bench_function("part2", |b| {
-        b.iter_batched(|| challenge.clone(), Challenge::part_two, BatchSize::SmallInput)
-    });
+    group.bench_function("part1", |b| b.iter(|| challenge.part_one()));
+    group.bench_function("part2", |b| b.iter(|| challenge.part_two()));
 
     group.finish();
 }
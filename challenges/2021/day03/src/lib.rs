@@ -1,6 +1,4 @@
-#![feature(drain_filter)]
-
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, Parser as ChallengeParser, Unparse};
 use nom::{combinator::recognize, IResult, Parser};
 use parsers::{binary, lines};
 
@@ -17,10 +15,21 @@ impl<'i> ChallengeParser<'i> for Day03 {
     }
 }
 
+impl Unparse for Day03 {
+    fn unparse(&self) -> String {
+        let width = self.bit_len;
+        self.data.iter().map(|d| format!("{d:0width$b}\n")).collect()
+    }
+}
+
 impl Challenge for Day03 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day03";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
 
-    fn part_one(self) -> usize {
+    fn part_one(&self) -> usize {
         let n = self.bit_len;
 
         let counts = self.data.iter().fold(vec![0; n], |mut counts, d| {
@@ -51,7 +60,7 @@ impl Challenge for Day03 {
         gamma * epsilon
     }
 
-    fn part_two(self) -> usize {
+    fn part_two(&self) -> usize {
         let n = self.bit_len;
 
         let mut oxy = self.data.clone();
@@ -63,14 +72,14 @@ impl Challenge for Day03 {
 
             let oxy_keep = if oxy_bit < (oxy.len() - oxy_bit) { 0 } else { bit };
 
-            oxy.drain_filter(|x| *x & bit != oxy_keep).for_each(|_| {});
+            oxy.retain(|x| *x & bit == oxy_keep);
 
             if oxy.len() == 1 {
                 break;
             }
         }
 
-        let mut co2 = self.data;
+        let mut co2 = self.data.clone();
         let mut bit = 1 << n;
         while bit > 1 {
             bit >>= 1;
@@ -79,7 +88,7 @@ impl Challenge for Day03 {
 
             let co2_keep = if co2_bit >= (co2.len() - co2_bit) { 0 } else { bit };
 
-            co2.drain_filter(|x| *x & bit != co2_keep).for_each(|_| {});
+            co2.retain(|x| *x & bit == co2_keep);
 
             if co2.len() == 1 {
                 break;
@@ -90,9 +99,11 @@ impl Challenge for Day03 {
     }
 }
 
+aoc::examples!(Day03);
+
 #[cfg(test)]
 mod tests {
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser};
 
     use crate::Day03;
 
@@ -137,4 +148,16 @@ mod tests {
         let output = Day03::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 230)
     }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let bit_len = rng.gen_range(1..16);
+            let data = (0..rng.gen_range(1..50)).map(|_| rng.gen_range(0..1 << bit_len));
+            Day03 {
+                bit_len,
+                data: data.collect(),
+            }
+        });
+    }
 }
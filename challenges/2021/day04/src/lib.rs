@@ -1,8 +1,7 @@
-#![feature(drain_filter)]
 use std::{fmt::Debug, str::FromStr};
 
 use ansi_term::Style;
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, Parser as ChallengeParser, Unparse};
 use nom::{
     bytes::complete::{tag, take},
     IResult, Parser,
@@ -80,14 +79,36 @@ impl<'i> ChallengeParser<'i> for Day04 {
     }
 }
 
+impl Unparse for Day04 {
+    fn unparse(&self) -> String {
+        let numbers: Vec<_> = self.numbers.iter().map(usize::to_string).collect();
+        let boards: Vec<_> = self
+            .boards
+            .iter()
+            .map(|Board(rows)| {
+                let rows = rows.iter().map(|Row(cells)| {
+                    let cells: Vec<_> = cells.iter().map(|cell| format!("{:2}", cell.number)).collect();
+                    cells.join(" ") + "\n"
+                });
+                rows.collect::<String>()
+            })
+            .collect();
+        format!("{}\n\n{}", numbers.join(","), boards.join("\n"))
+    }
+}
+
 impl Challenge for Day04 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day04";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
 
-    fn part_one(self) -> usize {
-        let mut boards = self.boards;
+    fn part_one(&self) -> usize {
+        let mut boards = self.boards.clone();
         self.numbers
-            .into_iter()
-            .find_map(|n| {
+            .iter()
+            .find_map(|&n| {
                 // find the first winner
                 boards
                     .iter_mut()
@@ -96,13 +117,13 @@ impl Challenge for Day04 {
             .unwrap()
     }
 
-    fn part_two(self) -> usize {
-        let mut boards = self.boards;
+    fn part_two(&self) -> usize {
+        let mut boards = self.boards.clone();
         self.numbers
-            .into_iter()
-            .find_map(|n| {
+            .iter()
+            .find_map(|&n| {
                 // find the last winner
-                let completed = boards.drain_filter(|board| board.is_bingo(n)).last();
+                let completed = boards.extract_if(.., |board| board.is_bingo(n)).last();
                 boards
                     .is_empty()
                     .then_some(completed)
@@ -113,11 +134,36 @@ impl Challenge for Day04 {
     }
 }
 
+impl Debug for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let style = Style {
+            is_strikethrough: self.marked,
+            ..Style::default()
+        };
+        let n = format!("{:02}", self.number);
+        write!(f, "{}", style.paint(n))
+    }
+}
+
+impl Debug for Row {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|cell| write!(f, "{:?} ", cell))
+    }
+}
+
+impl Debug for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|row| write!(f, "\n{:?}", row))
+    }
+}
+
+aoc::examples!(Day04);
+
 #[cfg(test)]
 mod tests {
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser};
 
-    use crate::Day04;
+    use crate::{Board, Cell, Day04, Row};
 
     const INPUT: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 
@@ -157,27 +203,23 @@ mod tests {
         let output = Day04::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 1924);
     }
-}
-
-impl Debug for Cell {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let style = Style {
-            is_strikethrough: self.marked,
-            ..Style::default()
-        };
-        let n = format!("{:02}", self.number);
-        write!(f, "{}", style.paint(n))
-    }
-}
-
-impl Debug for Row {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.iter().try_for_each(|cell| write!(f, "{:?} ", cell))
-    }
-}
 
-impl Debug for Board {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.iter().try_for_each(|row| write!(f, "\n{:?}", row))
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let numbers = (0..rng.gen_range(1..30)).map(|_| rng.gen_range(0..100)).collect();
+            let boards = (0..rng.gen_range(1..5)).map(|_| {
+                Board([(); 5].map(|_| {
+                    Row([(); 5].map(|_| Cell {
+                        marked: false,
+                        number: rng.gen_range(0..100),
+                    }))
+                }))
+            });
+            Day04 {
+                numbers,
+                boards: boards.collect(),
+            }
+        });
     }
 }
@@ -0,0 +1,45 @@
+use aoc::{
+    rand::{rngs::StdRng, SeedableRng},
+    Challenge, Generate, Parser,
+};
+use aoc2021_day05::Day05;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+pub fn day05(c: &mut Criterion) {
+    let mut group = c.benchmark_group(Day05::NAME);
+
+    let input = include_str!("../input.txt");
+    let challenge = Day05::parse(input).unwrap().1;
+
+    group.bench_function("parse", |b| b.iter(|| Day05::parse(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| challenge.part_one()));
+    group.bench_function("part2", |b| b.iter(|| challenge.part_two()));
+
+    group.finish();
+}
+
+pub fn day05_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("{}/scaling", Day05::NAME));
+    let mut rng = StdRng::seed_from_u64(5);
+
+    // up to ten times the real input's 500 lines
+    for size in [5, 50, 500, 5000] {
+        let input = Day05::generate(&mut rng, size);
+        let challenge = Day05::parse(&input).unwrap().1;
+
+        group.bench_with_input(BenchmarkId::new("parse", size), &input, |b, input| {
+            b.iter(|| Day05::parse(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part1", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_one())
+        });
+        group.bench_with_input(BenchmarkId::new("part2", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_two())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day05, day05_scaling);
+criterion_main!(benches);
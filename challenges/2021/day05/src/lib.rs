@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use aoc::{
+    rand::{Rng, RngCore},
+    Challenge, Generate, Parser as ChallengeParser, Unparse,
+};
+use derive_more::{Add, Sub};
+use nom::{bytes::complete::tag, character::complete::line_ending, IResult, Parser};
+use parsers::{number, ParserExt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Add, Sub)]
+pub struct Coords {
+    x: isize,
+    y: isize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Day05(Vec<(Coords, Coords)>);
+
+impl<'i> ChallengeParser<'i> for Day05 {
+    fn parse(input: &'i str) -> IResult<&'i str, Self> {
+        let coords = number.skip(tag(",")).and(number).map(|(x, y)| Coords { x, y });
+        let line = coords.separated_array(tag(" -> ")).map(|[a, b]| (a, b));
+        line.separated_list1(line_ending).map(Self).parse(input)
+    }
+}
+
+impl Unparse for Day05 {
+    fn unparse(&self) -> String {
+        self.0
+            .iter()
+            .map(|(a, b)| format!("{},{} -> {},{}\n", a.x, a.y, b.x, b.y))
+            .collect()
+    }
+}
+
+impl Challenge for Day05 {
+    const NAME: &'static str = "day05";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn part_one(&self) -> usize {
+        count_intersections(self.0.iter().copied().filter(|(p1, p2)| p1.x == p2.x || p1.y == p2.y))
+    }
+
+    fn part_two(&self) -> usize {
+        count_intersections(self.0.iter().copied())
+    }
+}
+
+fn count_intersections(iter: impl Iterator<Item = (Coords, Coords)>) -> usize {
+    let mut sparse = HashMap::new();
+
+    iter.for_each(|(p1, p2)| {
+        let mut i = p1;
+        let dir = {
+            let Coords { x, y } = p2 - p1;
+            Coords {
+                x: x.clamp(-1, 1),
+                y: y.clamp(-1, 1),
+            }
+        };
+
+        loop {
+            *sparse.entry(i).or_insert(0) += 1;
+            if i == p2 {
+                break;
+            }
+            i = i + dir;
+        }
+    });
+
+    sparse.into_iter().filter(|(_, count)| *count > 1).count()
+}
+
+impl Generate for Day05 {
+    /// `size` horizontal, vertical and diagonal lines on a 1000 by 1000 grid
+    fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let [x1, y1, mut x2, mut y2]: [isize; 4] = [0; 4].map(|_| rng.gen_range(0..1000));
+                match rng.gen_range(0..3) {
+                    0 => y2 = y1,
+                    1 => x2 = x1,
+                    // walk the same distance on both axes, as far as the grid allows
+                    _ => {
+                        let length = x1.abs_diff(x2).min(y1.abs_diff(y2)) as isize;
+                        x2 = x1 + length * (x2 - x1).signum();
+                        y2 = y1 + length * (y2 - y1).signum();
+                    }
+                }
+                format!("{x1},{y1} -> {x2},{y2}\n")
+            })
+            .collect()
+    }
+}
+
+aoc::examples!(Day05);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use aoc::{
+        rand::{rngs::StdRng, Rng, SeedableRng},
+        Challenge, Generate, Parser,
+    };
+
+    use crate::Day05;
+
+    const INPUT: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn parse() {
+        let output = Day05::parse(INPUT).unwrap().1;
+        dbg!(&output);
+    }
+    #[test]
+    fn part_one() {
+        let output = Day05::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_one(), 5);
+    }
+    #[test]
+    fn part_two() {
+        let output = Day05::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_two(), 12);
+    }
+
+    #[test]
+    fn generated() {
+        let input = Day05::generate(&mut StdRng::seed_from_u64(5), 200);
+        let lines: Vec<[i32; 4]> = input
+            .lines()
+            .map(|line| {
+                let numbers = line.split(|c: char| !c.is_ascii_digit()).filter(|n| !n.is_empty());
+                let numbers: Vec<i32> = numbers.map(|n| n.parse().unwrap()).collect();
+                numbers.try_into().unwrap()
+            })
+            .collect();
+        // walk every point of every line, counting the ones covered more than once
+        let overlaps = |diagonals: bool| {
+            let mut covered = HashMap::new();
+            for &[x1, y1, x2, y2] in &lines {
+                if x1 != x2 && y1 != y2 && !diagonals {
+                    continue;
+                }
+                let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+                for i in 0..=(x2 - x1).abs().max((y2 - y1).abs()) {
+                    *covered.entry((x1 + i * dx, y1 + i * dy)).or_insert(0) += 1;
+                }
+            }
+            covered.values().filter(|&&n| n > 1).count()
+        };
+
+        let output = Day05::parse(&input).unwrap().1;
+        assert_eq!(output.part_one(), overlaps(false));
+        assert_eq!(output.part_two(), overlaps(true));
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let size = rng.gen_range(1..50);
+            aoc::parse::<Day05>(&Day05::generate(rng, size)).unwrap()
+        });
+    }
+}
@@ -1,8 +1,8 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, Parser as ChallengeParser, Unparse};
 use nom::{bytes::complete::tag, IResult, Parser};
 use parsers::{number, ParserExt};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Day06(Vec<usize>);
 
 impl<'i> ChallengeParser<'i> for Day06 {
@@ -11,20 +11,31 @@ impl<'i> ChallengeParser<'i> for Day06 {
     }
 }
 
+impl Unparse for Day06 {
+    fn unparse(&self) -> String {
+        let numbers: Vec<_> = self.0.iter().map(|n| n.to_string()).collect();
+        format!("{}\n", numbers.join(","))
+    }
+}
+
 impl Challenge for Day06 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day06";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
 
-    fn part_one(self) -> usize {
-        process(80, self.0)
+    fn part_one(&self) -> usize {
+        process(80, &self.0)
     }
 
-    fn part_two(self) -> usize {
-        process(256, self.0)
+    fn part_two(&self) -> usize {
+        process(256, &self.0)
     }
 }
 
-fn process(n: usize, fish: Vec<usize>) -> usize {
-    let mut buckets = fish.into_iter().fold([0usize; 9], |mut b, f| {
+fn process(n: usize, fish: &[usize]) -> usize {
+    let mut buckets = fish.iter().fold([0usize; 9], |mut b, &f| {
         b[f] += 1;
         b
     });
@@ -37,10 +48,12 @@ fn process(n: usize, fish: Vec<usize>) -> usize {
     buckets.into_iter().sum()
 }
 
+aoc::examples!(Day06);
+
 #[cfg(test)]
 mod tests {
     use super::Day06;
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser};
 
     const INPUT: &str = "3,4,3,1,2";
 
@@ -61,4 +74,9 @@ mod tests {
         let output = Day06::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 26984457539);
     }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| Day06((0..rng.gen_range(1..50)).map(|_| rng.gen_range(0..2000)).collect()));
+    }
 }
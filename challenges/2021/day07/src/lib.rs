@@ -1,4 +1,4 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, Parser as ChallengeParser, Unparse};
 use nom::{bytes::complete::tag, IResult, Parser};
 use parsers::{number, ParserExt};
 
@@ -11,17 +11,29 @@ impl<'i> ChallengeParser<'i> for Day07 {
     }
 }
 
+impl Unparse for Day07 {
+    fn unparse(&self) -> String {
+        let numbers: Vec<_> = self.0.iter().map(|n| n.to_string()).collect();
+        format!("{}\n", numbers.join(","))
+    }
+}
+
 impl Challenge for Day07 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day07";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
 
-    fn part_one(mut self) -> usize {
+    fn part_one(&self) -> usize {
         // median
-        let index = self.0.len() / 2;
-        let pos = *self.0.select_nth_unstable(index).1;
-        self.0.into_iter().map(|p| p.abs_diff(pos)).sum()
+        let mut crabs = self.0.clone();
+        let index = crabs.len() / 2;
+        let pos = *crabs.select_nth_unstable(index).1;
+        crabs.into_iter().map(|p| p.abs_diff(pos)).sum()
     }
 
-    fn part_two(self) -> usize {
+    fn part_two(&self) -> usize {
         // mean
         let s = self.0.iter().sum::<usize>();
         let low = s / self.0.len();
@@ -32,10 +44,12 @@ impl Challenge for Day07 {
     }
 }
 
+aoc::examples!(Day07);
+
 #[cfg(test)]
 mod tests {
     use super::Day07;
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser};
 
     const INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
 
@@ -56,4 +70,9 @@ mod tests {
         let output = Day07::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 168);
     }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| Day07((0..rng.gen_range(1..50)).map(|_| rng.gen_range(0..2000)).collect()));
+    }
 }
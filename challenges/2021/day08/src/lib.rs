@@ -0,0 +1,183 @@
+use aoc::{Challenge, ChallengeError, Parser as ChallengeParser, Unparse};
+use nom::{
+    bytes::complete::{is_a, tag},
+    character::complete::line_ending,
+    IResult, Parser,
+};
+use parsers::ParserExt;
+
+#[derive(Debug, PartialEq, Clone)]
+struct Entry {
+    signals: [u8; 10],
+    outputs: [u8; 4],
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Day08(Vec<Entry>);
+
+impl<'i> ChallengeParser<'i> for Day08 {
+    fn parse(input: &'i str) -> IResult<&'i str, Self> {
+        let segments1 = is_a("abcdefg").map(to_bits).separated_array(tag(" "));
+        let segments2 = is_a("abcdefg").map(to_bits).separated_array(tag(" "));
+        let entry = segments1
+            .skip(tag(" | "))
+            .and(segments2)
+            .map(|(signals, outputs)| Entry { signals, outputs });
+
+        entry.separated_list1(line_ending).map(Self).parse(input)
+    }
+}
+
+impl Unparse for Day08 {
+    fn unparse(&self) -> String {
+        let segments = |bits: &[u8]| bits.iter().map(|&bits| to_segments(bits)).collect::<Vec<_>>().join(" ");
+        self.0
+            .iter()
+            .map(|entry| format!("{} | {}\n", segments(&entry.signals), segments(&entry.outputs)))
+            .collect()
+    }
+}
+
+fn to_bits(s: &str) -> u8 {
+    let mut bits: u8 = 0;
+    for b in s.bytes() {
+        let b = b - b'a';
+        bits |= 1 << b;
+    }
+    bits
+}
+
+fn to_segments(bits: u8) -> String {
+    (0..7)
+        .filter(|b| bits >> b & 1 == 1)
+        .map(|b| char::from(b'a' + b))
+        .collect()
+}
+
+impl Challenge for Day08 {
+    const NAME: &'static str = "day08";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = Result<usize, ChallengeError>;
+
+    fn part_one(&self) -> usize {
+        self.0
+            .iter()
+            .flat_map(|entry| {
+                entry
+                    .outputs
+                    .iter()
+                    .filter(|output| matches!(output.count_ones(), 2 | 4 | 3 | 7))
+            })
+            .count()
+    }
+
+    fn part_two(&self) -> Result<usize, ChallengeError> {
+        self.0.iter().map(Entry::decode).sum()
+    }
+}
+
+impl Entry {
+    /// The four digit output value, working out which signal is which digit from how they overlap
+    fn decode(&self) -> Result<usize, ChallengeError> {
+        let with_segments = |n| self.signals.into_iter().filter(move |s| s.count_ones() == n);
+        let unique = |n| match with_segments(n).collect::<Vec<_>>()[..] {
+            [signal] => Ok(signal),
+            _ => Err(ChallengeError::InvalidInput(format!(
+                "expected exactly one signal with {n} segments"
+            ))),
+        };
+        let contains = |signal: u8, other: u8| signal & other == other;
+
+        let mut digits = [0; 10];
+        for (digit, segments) in [(1, 2), (4, 4), (7, 3), (8, 7)] {
+            digits[digit] = unique(segments)?;
+        }
+        for signal in with_segments(6) {
+            let digit = if contains(signal, digits[4]) {
+                9
+            } else if contains(signal, digits[1]) {
+                0
+            } else {
+                6
+            };
+            digits[digit] = signal;
+        }
+        for signal in with_segments(5) {
+            let digit = if contains(signal, digits[1]) {
+                3
+            } else if (signal & digits[4]).count_ones() == 3 {
+                5
+            } else {
+                2
+            };
+            digits[digit] = signal;
+        }
+
+        self.outputs.iter().try_fold(0, |value, output| {
+            let digit = digits.iter().position(|digit| digit == output).ok_or_else(|| {
+                ChallengeError::InvalidInput(format!("{} is not one of the signals", to_segments(*output)))
+            })?;
+            Ok(value * 10 + digit)
+        })
+    }
+}
+
+aoc::examples!(Day08);
+
+#[cfg(test)]
+mod tests {
+    use super::{Day08, Entry};
+    use aoc::{rand::Rng, Challenge, Parser};
+
+    const INPUT: &str = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+";
+
+    #[test]
+    fn parse() {
+        let output = Day08::parse(INPUT).unwrap().1;
+        println!("{:?}", output);
+    }
+
+    #[test]
+    fn part_one() {
+        let output = Day08::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_one(), 26);
+    }
+
+    #[test]
+    fn part_two() {
+        let output = Day08::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_two(), Ok(61229));
+    }
+
+    #[test]
+    fn parse_entry() {
+        let output =
+            Day08::parse("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf\n")
+                .unwrap()
+                .1;
+        assert_eq!(output.part_two(), Ok(5353));
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let entries = (0..rng.gen_range(1..20)).map(|_| Entry {
+                signals: std::array::from_fn(|_| rng.gen_range(1..128)),
+                outputs: std::array::from_fn(|_| rng.gen_range(1..128)),
+            });
+            Day08(entries.collect())
+        });
+    }
+}
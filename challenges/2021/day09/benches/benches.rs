@@ -0,0 +1,46 @@
+use aoc::{
+    rand::{rngs::StdRng, SeedableRng},
+    Challenge, Generate, Parser,
+};
+use aoc2021_day09::Day09;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+pub fn day09(c: &mut Criterion) {
+    let mut group = c.benchmark_group(Day09::NAME);
+
+    let input = include_str!("../input.txt");
+    let challenge = Day09::parse(input).unwrap().1;
+
+    group.bench_function("parse", |b| b.iter(|| Day09::parse(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| challenge.part_one()));
+    group.bench_function("part2", |b| b.iter(|| challenge.part_two()));
+
+    group.finish();
+}
+
+pub fn day09_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("{}/scaling", Day09::NAME));
+    let mut rng = StdRng::seed_from_u64(9);
+
+    // sizes are the side of the heightmap, so each has about ten times the cells of the last,
+    // up to ten times the real input's 100 by 100
+    for size in [10, 32, 100, 320] {
+        let input = Day09::generate(&mut rng, size);
+        let challenge = Day09::parse(&input).unwrap().1;
+
+        group.bench_with_input(BenchmarkId::new("parse", size), &input, |b, input| {
+            b.iter(|| Day09::parse(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part1", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_one())
+        });
+        group.bench_with_input(BenchmarkId::new("part2", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_two())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day09, day09_scaling);
+criterion_main!(benches);
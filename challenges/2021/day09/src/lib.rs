@@ -0,0 +1,222 @@
+use aoc::{
+    rand::{Rng, RngCore},
+    Challenge, ChallengeError, Generate, Parser as ChallengeParser, Unparse,
+};
+use nom::{
+    character::complete::{line_ending, one_of},
+    IResult, Parser,
+};
+use parsers::ParserExt;
+#[derive(Debug, PartialEq, Clone)]
+pub struct Day09(Vec<Vec<u32>>);
+
+impl<'i> ChallengeParser<'i> for Day09 {
+    fn parse(input: &'i str) -> IResult<&'i str, Self> {
+        one_of("0123456789")
+            .map_res(|c| c.to_digit(10).ok_or(()))
+            .many1()
+            .separated_list0(line_ending)
+            .map(Self)
+            .parse(input)
+    }
+}
+
+impl Unparse for Day09 {
+    fn unparse(&self) -> String {
+        let mut input = String::new();
+        for row in &self.0 {
+            input.extend(row.iter().map(|&height| char::from_digit(height, 10).unwrap()));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+impl Challenge for Day09 {
+    const NAME: &'static str = "day09";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = Result<usize, ChallengeError>;
+
+    fn part_one(&self) -> usize {
+        let mut risk = 0;
+        for (y, row) in self.0.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let mut to_check = vec![];
+                if y > 0 {
+                    to_check.push((x, y - 1));
+                }
+                if y < self.0.len() - 1 {
+                    to_check.push((x, y + 1));
+                }
+                if x > 0 {
+                    to_check.push((x - 1, y));
+                }
+                if x < row.len() - 1 {
+                    to_check.push((x + 1, y));
+                }
+
+                if to_check.into_iter().all(|(x, y)| self.0[y][x] > *cell) {
+                    risk += *cell + 1;
+                }
+            }
+        }
+
+        risk as usize
+    }
+
+    fn part_two(&self) -> Result<usize, ChallengeError> {
+        let mut sinks = vec![];
+        for (y, row) in self.0.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let mut to_check = vec![];
+                if y > 0 {
+                    to_check.push((x, y - 1));
+                }
+                if y < self.0.len() - 1 {
+                    to_check.push((x, y + 1));
+                }
+                if x > 0 {
+                    to_check.push((x - 1, y));
+                }
+                if x < row.len() - 1 {
+                    to_check.push((x + 1, y));
+                }
+
+                if to_check.into_iter().all(|(x1, y1)| self.0[y1][x1] > *cell) {
+                    sinks.push((x, y));
+                }
+            }
+        }
+
+        let mut cells = self.0.clone();
+        let mut basins = sinks
+            .into_iter()
+            .map(|(x, y)| count(&mut cells, (x as isize, y as isize)))
+            .collect::<Vec<_>>();
+
+        let n = basins.len();
+        if n < 3 {
+            return Err(ChallengeError::NoSolution);
+        }
+        let (_, n3, n21) = basins.select_nth_unstable(n - 3);
+        Ok(*n3 * n21[0] * n21[1])
+    }
+}
+
+fn count(cells: &mut [Vec<u32>], pos: (isize, isize)) -> usize {
+    let (x, y) = pos;
+    if x < 0 || y < 0 || y >= cells.len() as isize || x >= cells[0].len() as isize {
+        return 0;
+    }
+
+    if cells[y as usize][x as usize] >= 9 {
+        return 0;
+    }
+
+    // mark cell
+    cells[y as usize][x as usize] = 10;
+
+    1 + count(cells, (x + 1, y)) + count(cells, (x, y + 1)) + count(cells, (x - 1, y)) + count(cells, (x, y - 1))
+}
+
+impl Generate for Day09 {
+    /// A `size` by `size` heightmap
+    fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+        let mut input = String::with_capacity(size * (size + 1));
+        for _ in 0..size {
+            input.extend((0..size).map(|_| char::from(b'0' + rng.gen_range(0..10))));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+aoc::examples!(Day09);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::Day09;
+    use aoc::{
+        rand::{rngs::StdRng, Rng, SeedableRng},
+        Challenge, ChallengeError, Generate, Parser,
+    };
+
+    const INPUT: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+    #[test]
+    fn parse() {
+        let output = Day09::parse(INPUT).unwrap().1;
+        println!("{:?}", output);
+    }
+
+    #[test]
+    fn part_one() {
+        let output = Day09::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_one(), 15);
+    }
+
+    #[test]
+    fn part_two() {
+        let output = Day09::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_two(), Ok(1134));
+
+        // small heightmaps, like generated ones, can have fewer than three basins
+        let output = Day09::parse("19\n90\n").unwrap().1;
+        assert_eq!(output.part_two(), Err(ChallengeError::NoSolution));
+    }
+
+    #[test]
+    fn generated() {
+        let input = Day09::generate(&mut StdRng::seed_from_u64(9), 100);
+        let heights: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let neighbours = |(r, c): (usize, usize)| {
+            let around = [(r.wrapping_sub(1), c), (r + 1, c), (r, c.wrapping_sub(1)), (r, c + 1)];
+            around
+                .into_iter()
+                .filter(|&(r, c)| r < heights.len() && c < heights[r].len())
+        };
+        let cells = (0..heights.len()).flat_map(|r| (0..heights[r].len()).map(move |c| (r, c)));
+
+        let low_points = cells
+            .clone()
+            .filter(|&p| neighbours(p).all(|(r, c)| heights[r][c] > heights[p.0][p.1]));
+        let risk: usize = low_points.map(|(r, c)| (heights[r][c] - b'0') as usize + 1).sum();
+
+        // basins are whatever is walled off by nines
+        let mut seen = HashSet::new();
+        let mut basins: Vec<usize> = vec![];
+        for start in cells.filter(|&(r, c)| heights[r][c] != b'9') {
+            let mut stack = vec![start];
+            let mut size = 0;
+            while let Some(p) = stack.pop() {
+                if seen.insert(p) {
+                    size += 1;
+                    stack.extend(neighbours(p).filter(|&(r, c)| heights[r][c] != b'9'));
+                }
+            }
+            basins.push(size);
+        }
+        basins.sort_unstable_by(|a, b| b.cmp(a));
+
+        let output = Day09::parse(&input).unwrap().1;
+        assert_eq!(output.part_one(), risk);
+        assert_eq!(output.part_two(), Ok(basins[..3].iter().product::<usize>()));
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let size = rng.gen_range(1..50);
+            aoc::parse::<Day09>(&Day09::generate(rng, size)).unwrap()
+        });
+    }
+}
@@ -1,4 +1,4 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, Parser as ChallengeParser, Unparse};
 use nom::{branch::alt, character::complete::line_ending, IResult, Parser};
 use parsers::ParserExt;
 
@@ -10,7 +10,7 @@ enum Delim {
     Angle, // <>
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 enum State {
     Open(Delim),
     Close(Delim),
@@ -43,13 +43,13 @@ fn parse_chunk(input: &str) -> IResult<&str, Chunk> {
         .parse(input)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Chunk(Vec<State>);
 
 impl Chunk {
-    fn corrupted(self) -> usize {
+    fn corrupted(&self) -> usize {
         let mut stack = vec![];
-        for state in self.0 {
+        for &state in &self.0 {
             match state {
                 State::Open(open) => stack.push(open),
                 State::Close(close) => {
@@ -66,9 +66,9 @@ impl Chunk {
         }
         0
     }
-    fn incomplete(self) -> usize {
+    fn incomplete(&self) -> usize {
         let mut stack = vec![];
-        for state in self.0 {
+        for &state in &self.0 {
             match state {
                 State::Open(open) => stack.push(open),
                 State::Close(close) => {
@@ -91,7 +91,7 @@ impl Chunk {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Day10(Vec<Chunk>);
 
 impl<'i> ChallengeParser<'i> for Day10 {
@@ -100,17 +100,41 @@ impl<'i> ChallengeParser<'i> for Day10 {
     }
 }
 
+impl Unparse for Day10 {
+    fn unparse(&self) -> String {
+        let mut input = String::new();
+        for chunk in &self.0 {
+            input.extend(chunk.0.iter().map(|state| match state {
+                State::Open(Delim::Paren) => '(',
+                State::Open(Delim::Brace) => '{',
+                State::Open(Delim::Brack) => '[',
+                State::Open(Delim::Angle) => '<',
+                State::Close(Delim::Paren) => ')',
+                State::Close(Delim::Brace) => '}',
+                State::Close(Delim::Brack) => ']',
+                State::Close(Delim::Angle) => '>',
+            }));
+            input.push('\n');
+        }
+        input
+    }
+}
+
 impl Challenge for Day10 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day10";
+    const YEAR: usize = 2021;
 
-    fn part_one(self) -> usize {
-        self.0.into_iter().map(Chunk::corrupted).sum()
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn part_one(&self) -> usize {
+        self.0.iter().map(Chunk::corrupted).sum()
     }
 
-    fn part_two(self) -> usize {
+    fn part_two(&self) -> usize {
         let mut scores = self
             .0
-            .into_iter()
+            .iter()
             .map(Chunk::incomplete)
             .filter(|&s| s > 0)
             .collect::<Vec<_>>();
@@ -119,12 +143,14 @@ impl Challenge for Day10 {
     }
 }
 
+aoc::examples!(Day10);
+
 #[cfg(test)]
 mod tests {
-    use crate::{parse_chunk, parse_close, parse_open, Delim};
+    use crate::{parse_chunk, parse_close, parse_open, Chunk, Delim, State};
 
     use super::Day10;
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser};
 
     const INPUT: &str = "[({(<(())[]>[[{[]{<()<>>
 [(()[<>])]({[<{<<[]>>(
@@ -231,4 +257,24 @@ mod tests {
         let output = Day10::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 288957);
     }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let delims = [Delim::Paren, Delim::Brace, Delim::Brack, Delim::Angle];
+            let mut chunks = vec![];
+            for _ in 0..rng.gen_range(1..10) {
+                let states = (0..rng.gen_range(1..30)).map(|_| {
+                    let delim = delims[rng.gen_range(0..4)];
+                    if rng.gen_bool(0.5) {
+                        State::Open(delim)
+                    } else {
+                        State::Close(delim)
+                    }
+                });
+                chunks.push(Chunk(states.collect()));
+            }
+            Day10(chunks)
+        });
+    }
 }
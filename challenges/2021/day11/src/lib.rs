@@ -1,4 +1,4 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, ChallengeError, Parser as ChallengeParser, Unparse, Visualize};
 use nom::{
     character::complete::{line_ending, one_of},
     IResult, Parser,
@@ -19,10 +19,25 @@ impl<'i> ChallengeParser<'i> for Day11 {
     }
 }
 
+impl Unparse for Day11 {
+    fn unparse(&self) -> String {
+        let mut input = String::new();
+        for row in self.0 {
+            input.extend(row.map(|energy| char::from(b'0' + energy)));
+            input.push('\n');
+        }
+        input
+    }
+}
+
 impl Challenge for Day11 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day11";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
 
-    fn part_one(self) -> usize {
+    fn part_one(&self) -> usize {
         let mut grid = self.0;
         let mut flashes = 0;
         for _ in 0..100 {
@@ -31,7 +46,7 @@ impl Challenge for Day11 {
         flashes
     }
 
-    fn part_two(self) -> usize {
+    fn part_two(&self) -> usize {
         let mut grid = self.0;
         let mut i = 1;
         loop {
@@ -44,6 +59,32 @@ impl Challenge for Day11 {
     }
 }
 
+impl Visualize for Day11 {
+    /// Each step up to the first synchronised flash, with the octopi that just flashed in bold
+    fn frames(&self) -> Result<impl Iterator<Item = String> + '_, ChallengeError> {
+        let mut grid = self.0;
+        let mut synchronised = false;
+        Ok((1..).map_while(move |step| {
+            if synchronised {
+                return None;
+            }
+            synchronised = flash_step(&mut grid) == 100;
+
+            let mut frame = format!("step {step}\n");
+            for row in grid {
+                for energy in row {
+                    match energy {
+                        0 => frame.push_str("\x1b[1m0\x1b[0m"),
+                        _ => frame.push((b'0' + energy) as char),
+                    }
+                }
+                frame.push('\n');
+            }
+            Some(frame)
+        }))
+    }
+}
+
 fn flash_step(grid: &mut [[u8; 10]; 10]) -> usize {
     // keep track
     let mut flashes = 0;
@@ -104,10 +145,12 @@ fn flash_step(grid: &mut [[u8; 10]; 10]) -> usize {
     flashes
 }
 
+aoc::examples!(Day11);
+
 #[cfg(test)]
 mod tests {
     use super::Day11;
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser, Visualize};
 
     const INPUT: &str = "5483143223
 2745854711
@@ -138,4 +181,17 @@ mod tests {
         let output = Day11::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 195);
     }
+
+    #[test]
+    fn frames() {
+        let output = Day11::parse(INPUT).unwrap().1;
+        let frames: Vec<String> = output.frames().unwrap().collect();
+        assert_eq!(frames.len(), 195);
+        assert!(frames[194].starts_with("step 195\n\x1b[1m0"));
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| Day11([[0; 10]; 10].map(|row| row.map(|_| rng.gen_range(0..10)))));
+    }
 }
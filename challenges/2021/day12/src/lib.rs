@@ -1,4 +1,4 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, Parser as ChallengeParser, Unparse};
 use nom::{
     bytes::complete::tag,
     character::complete::{alpha1, line_ending},
@@ -21,14 +21,24 @@ impl<'i> ChallengeParser<'i> for Day12<'i> {
     }
 }
 
+impl Unparse for Day12<'_> {
+    fn unparse(&self) -> String {
+        self.0.iter().map(|(a, b)| format!("{a}-{b}\n")).collect()
+    }
+}
+
 impl Challenge for Day12<'_> {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day12";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
 
-    fn part_one(self) -> usize {
+    fn part_one(&self) -> usize {
         self.dfs(vec![], false)
     }
 
-    fn part_two(self) -> usize {
+    fn part_two(&self) -> usize {
         self.dfs(vec![], true)
     }
 }
@@ -75,10 +85,12 @@ impl<'a> Day12<'a> {
     }
 }
 
+aoc::examples!(Day12);
+
 #[cfg(test)]
 mod tests {
     use super::Day12;
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser};
 
     const INPUT: &str = "start-A
 start-b
@@ -106,4 +118,14 @@ b-end
         let output = Day12::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 36);
     }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let caves = ["start", "end", "A", "b", "HN", "dc", "kj"];
+            let edges = rng.gen_range(1..20);
+            let mut cave = || caves[rng.gen_range(0..caves.len())];
+            Day12((0..edges).map(|_| (cave(), cave())).collect())
+        });
+    }
 }
@@ -1,4 +1,4 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, ChallengeError, Parser as ChallengeParser, Unparse, Visualize};
 use nom::{
     bytes::complete::tag,
     character::complete::{line_ending, one_of},
@@ -27,21 +27,55 @@ impl<'i> ChallengeParser<'i> for Day13 {
     }
 }
 
+impl Unparse for Day13 {
+    fn unparse(&self) -> String {
+        let pairs: String = self.pairs.iter().map(|[x, y]| format!("{x},{y}\n")).collect();
+        let folds: String = self
+            .folds
+            .iter()
+            .map(|(axis, at)| format!("fold along {axis}={at}\n"))
+            .collect();
+        format!("{pairs}\n{folds}")
+    }
+}
+
 impl Challenge for Day13 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day13";
+    const YEAR: usize = 2021;
 
-    fn part_one(self) -> usize {
-        let Self { pairs, folds } = self;
+    type Output1 = usize;
+    type Output2 = Result<String, ChallengeError>;
+
+    fn part_one(&self) -> usize {
+        let fold = self.folds[0];
+        apply_fold(self.pairs.clone(), fold).len()
+    }
 
-        let fold = folds.into_iter().next().unwrap();
-        apply_fold(pairs, fold).len()
+    fn part_two(&self) -> Result<String, ChallengeError> {
+        self.part2()
     }
+}
+
+impl Visualize for Day13 {
+    /// The paper after each fold
+    fn frames(&self) -> Result<impl Iterator<Item = String> + '_, ChallengeError> {
+        Ok(self.folds.iter().scan(self.pairs.clone(), |pairs, &fold| {
+            *pairs = apply_fold(std::mem::take(pairs), fold);
+
+            let width = pairs.iter().map(|&[x, _]| x + 1).max().unwrap_or_default();
+            let height = pairs.iter().map(|&[_, y]| y + 1).max().unwrap_or_default();
+            let mut paper = vec![vec![b'.'; width]; height];
+            for &[x, y] in pairs.iter() {
+                paper[y][x] = b'#';
+            }
 
-    fn part_two(self) -> usize {
-        let code = self.part2();
-        // my auto submitter doesn't support strings just yet
-        // so I'm just gonna panic the output to submit manually
-        panic!("{}", code);
+            let mut frame = format!("fold along {}={}\n", fold.0, fold.1);
+            for row in paper {
+                frame.push_str(std::str::from_utf8(&row).unwrap());
+                frame.push('\n');
+            }
+            Some(frame)
+        }))
     }
 }
 
@@ -59,11 +93,8 @@ fn apply_fold(mut points: Vec<[usize; 2]>, (axis, index): (char, usize)) -> Vec<
 }
 
 impl Day13 {
-    pub fn part2(self) -> String {
-        let Self { pairs, folds } = self;
-        let pairs = folds
-            .into_iter()
-            .fold(pairs, apply_fold);
+    pub fn part2(&self) -> Result<String, ChallengeError> {
+        let pairs = self.folds.iter().copied().fold(self.pairs.clone(), apply_fold);
 
         // 'OCR'
         // Each letter fits in a 4 * 6 dot grid (with a space between to make 5 * 6)
@@ -78,8 +109,7 @@ impl Day13 {
         let mut offset = 0;
         for [x, y] in pairs {
             if x / 5 > offset {
-                let c = b'A' + LETTERS.iter().position(|&l| l == letter).unwrap() as u8;
-                string.push(c);
+                string.push(ocr(letter)?);
                 letter = 0;
                 offset = x / 5;
             }
@@ -87,10 +117,18 @@ impl Day13 {
             let index = (x % 5) + y * 4;
             letter |= 1 << index;
         }
-        let c = b'A' + LETTERS.iter().position(|&l| l == letter).unwrap() as u8;
-        string.push(c);
+        string.push(ocr(letter)?);
+
+        Ok(String::from_utf8(string).unwrap())
+    }
+}
 
-        String::from_utf8(string).unwrap()
+fn ocr(letter: u32) -> Result<u8, ChallengeError> {
+    match LETTERS.iter().position(|&l| l == letter) {
+        Some(i) => Ok(b'A' + i as u8),
+        None => Err(ChallengeError::InvalidInput(format!(
+            "the dots {letter:#026b} are not a known letter"
+        ))),
     }
 }
 
@@ -126,10 +164,12 @@ const LETTERS: [u32; 26] = [
     0b_0000_0000_0000_0000_0000_0000, // Z
 ];
 
+aoc::examples!(Day13);
+
 #[cfg(test)]
 mod tests {
     use super::Day13;
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser, Visualize};
 
     const INPUT: &str = "6,10
 0,14
@@ -165,4 +205,25 @@ fold along x=5
         let output = Day13::parse(INPUT).unwrap().1;
         assert_eq!(output.part_one(), 17);
     }
+
+    #[test]
+    fn frames() {
+        let output = Day13::parse(INPUT).unwrap().1;
+        let frames: Vec<String> = output.frames().unwrap().collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1], "fold along x=5\n#####\n#...#\n#...#\n#...#\n#####\n");
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let pairs = (0..rng.gen_range(1..50))
+                .map(|_| [rng.gen_range(0..1500), rng.gen_range(0..1500)])
+                .collect();
+            let folds = (0..rng.gen_range(1..12))
+                .map(|_| (['x', 'y'][rng.gen_range(0..2)], rng.gen_range(1..750)))
+                .collect();
+            Day13 { pairs, folds }
+        });
+    }
 }
@@ -1,7 +1,6 @@
-#![feature(array_windows)]
 use std::array;
 
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, Parser as ChallengeParser, Unparse};
 use nom::{
     bytes::complete::{tag, take},
     character::complete::{alpha1, line_ending},
@@ -33,14 +32,29 @@ impl<'i> ChallengeParser<'i> for Day14<'i> {
     }
 }
 
+impl<'i> Unparse for Day14<'i> {
+    fn unparse(&self) -> String {
+        let rules: String = self
+            .rules
+            .iter()
+            .map(|&([a, b], insert)| format!("{}{} -> {}\n", a as char, b as char, insert as char))
+            .collect();
+        format!("{}\n\n{rules}", String::from_utf8_lossy(self.polymer))
+    }
+}
+
 impl<'i> Challenge for Day14<'i> {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day14";
+    const YEAR: usize = 2021;
 
-    fn part_one(self) -> usize {
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn part_one(&self) -> usize {
         self.run(10)
     }
 
-    fn part_two(self) -> usize {
+    fn part_two(&self) -> usize {
         self.run(40)
     }
 }
@@ -50,7 +64,7 @@ fn ch(a: u8) -> usize {
 }
 
 impl<'i> Day14<'i> {
-    fn run(self, n: usize) -> usize {
+    fn run(&self, n: usize) -> usize {
         let mut pairs = [[0_usize; 26]; 26];
         self.polymer.array_windows().for_each(|&[a, b]| {
             pairs[ch(a)][ch(b)] += 1;
@@ -58,8 +72,8 @@ impl<'i> Day14<'i> {
 
         let mut rules = [[26; 26]; 26];
         self.rules
-            .into_iter()
-            .for_each(|([a, b], c)| rules[ch(a)][ch(b)] = c - b'A');
+            .iter()
+            .for_each(|&([a, b], c)| rules[ch(a)][ch(b)] = c - b'A');
 
         for _ in 0..n {
             pairs = step(pairs, &rules);
@@ -103,10 +117,12 @@ fn step(polymer: [[usize; 26]; 26], rules: &[[u8; 26]; 26]) -> [[usize; 26]; 26]
     new
 }
 
+aoc::examples!(Day14);
+
 #[cfg(test)]
 mod tests {
     use super::Day14;
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser};
 
     const INPUT: &str = "NNCB
 
@@ -145,4 +161,18 @@ CN -> C
         let output = Day14::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 2188189693529);
     }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let [length, rules] = [rng.gen_range(1..20), rng.gen_range(1..16)];
+            let mut element = || b"BCHN"[rng.gen_range(0..4)];
+            let polymer: Vec<u8> = (0..length).map(|_| element()).collect();
+            let rules = (0..rules).map(|_| ([element(), element()], element()));
+            Day14 {
+                polymer: polymer.leak(),
+                rules: rules.collect(),
+            }
+        });
+    }
 }
@@ -0,0 +1,48 @@
+use aoc::{
+    rand::{rngs::StdRng, SeedableRng},
+    Challenge, Generate, Parser,
+};
+use aoc2021_day15::Day15;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+pub fn day15(c: &mut Criterion) {
+    let mut group = c.benchmark_group(Day15::NAME);
+
+    let input = include_str!("../input.txt");
+    let challenge = Day15::parse(input).unwrap().1;
+
+    group.bench_function("parse", |b| b.iter(|| Day15::parse(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| challenge.part_one()));
+    group.bench_function("part2", |b| b.iter(|| challenge.part_two()));
+
+    group.finish();
+}
+
+pub fn day15_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("{}/scaling", Day15::NAME));
+    let mut rng = StdRng::seed_from_u64(15);
+
+    // part two searches a cave five times as wide, so the biggest input takes seconds an iteration
+    group.sample_size(10);
+    // sizes are the side of the cave, so each has about ten times the cells of the last,
+    // up to ten times the real input's 100 by 100
+    for size in [10, 32, 100, 320] {
+        let input = Day15::generate(&mut rng, size);
+        let challenge = Day15::parse(&input).unwrap().1;
+
+        group.bench_with_input(BenchmarkId::new("parse", size), &input, |b, input| {
+            b.iter(|| Day15::parse(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part1", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_one())
+        });
+        group.bench_with_input(BenchmarkId::new("part2", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_two())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day15, day15_scaling);
+criterion_main!(benches);
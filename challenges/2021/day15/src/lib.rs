@@ -0,0 +1,180 @@
+use aoc::{
+    rand::{Rng, RngCore},
+    Challenge, ChallengeError, Generate, Parser as ChallengeParser, Unparse,
+};
+use nom::{
+    character::{complete::one_of, streaming::line_ending},
+    IResult, Parser,
+};
+use parsers::ParserExt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Day15(Vec<Vec<usize>>);
+
+impl<'i> ChallengeParser<'i> for Day15 {
+    fn parse(input: &'i str) -> IResult<&'i str, Self> {
+        one_of("0123456789")
+            .map(|c| c.to_digit(10).unwrap() as usize)
+            .many1()
+            .separated_list1(line_ending)
+            .map(Self)
+            .parse(input)
+    }
+}
+
+impl Unparse for Day15 {
+    fn unparse(&self) -> String {
+        let mut input = String::new();
+        for row in &self.0 {
+            input.extend(row.iter().map(|&risk| char::from_digit(risk as u32, 10).unwrap()));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+impl Challenge for Day15 {
+    const NAME: &'static str = "day15";
+    const YEAR: usize = 2021;
+
+    type Output1 = Result<usize, ChallengeError>;
+    type Output2 = Result<usize, ChallengeError>;
+
+    fn part_one(&self) -> Result<usize, ChallengeError> {
+        let n = self.0.len();
+        let goal = (n - 1, n - 1);
+        self.minpath(goal)
+    }
+
+    fn part_two(&self) -> Result<usize, ChallengeError> {
+        let n = self.0.len();
+        let goal = (5 * n - 1, 5 * n - 1);
+        self.minpath(goal)
+    }
+}
+
+impl Day15 {
+    fn s(&self, x: usize, y: usize) -> usize {
+        let n = self.0.len();
+        let v = self.0[y % n][x % n];
+        (v + x / n + y / n - 1) % 9 + 1
+    }
+
+    fn minpath(&self, end: (usize, usize)) -> Result<usize, ChallengeError> {
+        use pathfinding::prelude::dijkstra;
+        let result = dijkstra(
+            &(0, 0),
+            |&(x, y)| {
+                let mut suc = vec![];
+                if x > 0 {
+                    suc.push((x - 1, y));
+                }
+                if y > 0 {
+                    suc.push((x, y - 1));
+                }
+                if x < end.0 {
+                    suc.push((x + 1, y));
+                }
+                if y < end.1 {
+                    suc.push((x, y + 1));
+                }
+                suc.into_iter().map(|(x, y)| ((x, y), self.s(x, y)))
+            },
+            |&p| p == end,
+        );
+        result.map(|(_, risk)| risk).ok_or(ChallengeError::NoSolution)
+    }
+}
+
+impl Generate for Day15 {
+    /// A `size` by `size` map of risk levels
+    fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+        let mut input = String::with_capacity(size * (size + 1));
+        for _ in 0..size {
+            input.extend((0..size).map(|_| char::from(b'0' + rng.gen_range(1..10))));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+aoc::examples!(Day15);
+
+#[cfg(test)]
+mod tests {
+    use super::Day15;
+    use aoc::{
+        rand::{rngs::StdRng, Rng, SeedableRng},
+        Challenge, Generate, Parser,
+    };
+
+    const INPUT: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    #[test]
+    fn parse() {
+        let output = Day15::parse(INPUT).unwrap().1;
+        println!("{:?}", output);
+    }
+
+    #[test]
+    fn part_one() {
+        let output = Day15::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_one(), Ok(40));
+    }
+
+    #[test]
+    fn part_two() {
+        let output = Day15::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_two(), Ok(315));
+    }
+
+    #[test]
+    fn generated() {
+        let input = Day15::generate(&mut StdRng::seed_from_u64(15), 20);
+        let risks: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        // relax every cell from its neighbours until nothing changes, rather than searching
+        let lowest = |tiles: usize| {
+            let n = risks.len();
+            let risk = |r: usize, c: usize| ((risks[r % n][c % n] - b'0') as usize + r / n + c / n - 1) % 9 + 1;
+            let mut total = vec![vec![usize::MAX; n * tiles]; n * tiles];
+            total[0][0] = 0;
+            let mut changed = true;
+            while changed {
+                changed = false;
+                for r in 0..n * tiles {
+                    for c in 0..n * tiles {
+                        let around = [(r.wrapping_sub(1), c), (r + 1, c), (r, c.wrapping_sub(1)), (r, c + 1)];
+                        let best = around.iter().filter_map(|&(r, c)| total.get(r)?.get(c)).min().unwrap();
+                        if best.saturating_add(risk(r, c)) < total[r][c] {
+                            total[r][c] = best + risk(r, c);
+                            changed = true;
+                        }
+                    }
+                }
+            }
+            total[n * tiles - 1][n * tiles - 1]
+        };
+
+        let output = Day15::parse(&input).unwrap().1;
+        assert_eq!(output.part_one(), Ok(lowest(1)));
+        assert_eq!(output.part_two(), Ok(lowest(5)));
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let size = rng.gen_range(1..50);
+            aoc::parse::<Day15>(&Day15::generate(rng, size)).unwrap()
+        });
+    }
+}
@@ -0,0 +1,45 @@
+use aoc::{
+    rand::{rngs::StdRng, SeedableRng},
+    Challenge, Generate, Parser,
+};
+use aoc2021_day16::Day16;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+pub fn day16(c: &mut Criterion) {
+    let mut group = c.benchmark_group(Day16::NAME);
+
+    let input = include_str!("../input.txt");
+    let challenge = Day16::parse(input).unwrap().1;
+
+    group.bench_function("parse", |b| b.iter(|| Day16::parse(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| challenge.part_one()));
+    group.bench_function("part2", |b| b.iter(|| challenge.part_two()));
+
+    group.finish();
+}
+
+pub fn day16_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("{}/scaling", Day16::NAME));
+    let mut rng = StdRng::seed_from_u64(16);
+
+    // up to ten times the real input, which has about 300 packets
+    for size in [3, 30, 300, 3000] {
+        let input = Day16::generate(&mut rng, size);
+        let challenge = Day16::parse(&input).unwrap().1;
+
+        group.bench_with_input(BenchmarkId::new("parse", size), &input, |b, input| {
+            b.iter(|| Day16::parse(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part1", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_one())
+        });
+        group.bench_with_input(BenchmarkId::new("part2", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_two())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day16, day16_scaling);
+criterion_main!(benches);
@@ -0,0 +1,356 @@
+use aoc::{
+    rand::{Rng, RngCore},
+    Challenge, Generate, Parser as ChallengeParser, Unparse,
+};
+use bitvec::{order::Msb0, prelude::BitVec, slice::BitSlice};
+use nom::{character::complete::one_of, IResult, Parser};
+use parsers::ParserExt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Day16(BitVec<u8, Msb0>);
+
+impl<'i> ChallengeParser<'i> for Day16 {
+    fn parse(input: &'i str) -> IResult<&'i str, Self> {
+        one_of("0123456789ABCDEF")
+            .map(|c| c.to_digit(16).unwrap() as u8)
+            .many1()
+            .map(|h| h.as_chunks().0.iter().map(|&[x, y]| x << 4 | y).collect())
+            .map(Self)
+            .parse(input)
+    }
+}
+
+impl Unparse for Day16 {
+    fn unparse(&self) -> String {
+        let mut input: String = self.0.as_raw_slice().iter().map(|byte| format!("{byte:02X}")).collect();
+        input.push('\n');
+        input
+    }
+}
+
+impl Challenge for Day16 {
+    const NAME: &'static str = "day16";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn part_one(&self) -> usize {
+        let (packet, _) = Packet::parse(&self.0);
+        packet.sum_ver()
+    }
+
+    fn part_two(&self) -> usize {
+        let (packet, _) = Packet::parse(&self.0);
+        packet.eval()
+    }
+}
+
+#[derive(Debug)]
+struct Packet {
+    ver: u8,
+    typ: PacketType,
+}
+
+#[derive(Debug)]
+enum PacketType {
+    Sum(Vec<Packet>),
+    Prod(Vec<Packet>),
+    Min(Vec<Packet>),
+    Max(Vec<Packet>),
+    Lit(BitVec<u8, Msb0>),
+    Gt(Vec<Packet>),
+    Lt(Vec<Packet>),
+    Eq(Vec<Packet>),
+}
+
+impl Packet {
+    fn sum_ver(&self) -> usize {
+        self.ver as usize
+            + match &self.typ {
+                PacketType::Lit(_) => 0,
+                PacketType::Sum(packets)
+                | PacketType::Prod(packets)
+                | PacketType::Min(packets)
+                | PacketType::Max(packets)
+                | PacketType::Gt(packets)
+                | PacketType::Lt(packets)
+                | PacketType::Eq(packets) => packets.iter().map(Packet::sum_ver).sum::<usize>(),
+            }
+    }
+
+    fn eval(&self) -> usize {
+        match &self.typ {
+            PacketType::Lit(lit) => lit.iter().fold(0, |v, b| v << 1 | (*b as usize)),
+            PacketType::Sum(packets) => packets.iter().map(Packet::eval).sum(),
+            PacketType::Prod(packets) => packets.iter().map(Packet::eval).product(),
+            PacketType::Min(packets) => packets.iter().map(Packet::eval).min().unwrap(),
+            PacketType::Max(packets) => packets.iter().map(Packet::eval).max().unwrap(),
+            PacketType::Gt(packets) => (packets[0].eval() > packets[1].eval()) as usize,
+            PacketType::Lt(packets) => (packets[0].eval() < packets[1].eval()) as usize,
+            PacketType::Eq(packets) => (packets[0].eval() == packets[1].eval()) as usize,
+        }
+    }
+
+    fn parse(bits: &BitSlice<u8, Msb0>) -> (Self, &BitSlice<u8, Msb0>) {
+        let (ver, bits) = read_u8(bits, 3);
+        let (typ, bits) = read_u8(bits, 3);
+
+        let (typ, bits) = match typ {
+            0 => parse_op(bits, PacketType::Sum),
+            1 => parse_op(bits, PacketType::Prod),
+            2 => parse_op(bits, PacketType::Min),
+            3 => parse_op(bits, PacketType::Max),
+            4 => parse_lit(bits),
+            5 => parse_op(bits, PacketType::Gt),
+            6 => parse_op(bits, PacketType::Lt),
+            7 => parse_op(bits, PacketType::Eq),
+            _ => unreachable!(),
+        };
+        (Self { ver, typ }, bits)
+    }
+}
+
+fn parse_lit(bits: &BitSlice<u8, Msb0>) -> (PacketType, &BitSlice<u8, Msb0>) {
+    let mut litvec = BitVec::new();
+
+    let mut bits = bits;
+    loop {
+        let (s, b0) = bits.split_at(1);
+        let (v, b1) = b0.split_at(4);
+        litvec.extend_from_bitslice(v);
+        bits = b1;
+        if !s.first().unwrap() {
+            break;
+        }
+    }
+
+    (PacketType::Lit(litvec), bits)
+}
+
+fn parse_op(
+    bits: &BitSlice<u8, Msb0>,
+    typ: impl FnOnce(Vec<Packet>) -> PacketType,
+) -> (PacketType, &BitSlice<u8, Msb0>) {
+    let (i, bits) = bits.split_at(1);
+    if *i.first().unwrap() {
+        parse_fixed_len_op(bits, typ)
+    } else {
+        parse_fixed_size_op(bits, typ)
+    }
+}
+
+fn parse_fixed_len_op(
+    bits: &BitSlice<u8, Msb0>,
+    typ: impl FnOnce(Vec<Packet>) -> PacketType,
+) -> (PacketType, &BitSlice<u8, Msb0>) {
+    let (l, mut bits) = read_u16(bits, 11);
+    let mut packets = vec![];
+    for _ in 0..l {
+        let (p, b) = Packet::parse(bits);
+        packets.push(p);
+        bits = b;
+    }
+
+    (typ(packets), bits)
+}
+
+fn parse_fixed_size_op(
+    bits: &BitSlice<u8, Msb0>,
+    typ: impl FnOnce(Vec<Packet>) -> PacketType,
+) -> (PacketType, &BitSlice<u8, Msb0>) {
+    let (l, bits) = read_u16(bits, 15);
+    let (mut bits1, bits) = bits.split_at(l as usize);
+    let mut packets = vec![];
+    while !bits1.is_empty() {
+        let (p, b) = Packet::parse(bits1);
+        packets.push(p);
+        bits1 = b;
+    }
+
+    (typ(packets), bits)
+}
+
+fn read_u8(bits: &BitSlice<u8, Msb0>, n: usize) -> (u8, &BitSlice<u8, Msb0>) {
+    let (v, bits) = bits.split_at(n);
+    let v = v.iter().fold(0, |v, b| v << 1 | (*b as u8));
+    (v, bits)
+}
+
+fn read_u16(bits: &BitSlice<u8, Msb0>, n: usize) -> (u16, &BitSlice<u8, Msb0>) {
+    let (v, bits) = bits.split_at(n);
+    let v = v.iter().fold(0, |v, b| v << 1 | (*b as u16));
+    (v, bits)
+}
+
+impl Generate for Day16 {
+    /// A transmission of about `size` packets
+    fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+        let mut bits = BitVec::<u8, Msb0>::new();
+        generate_packet(rng, size.max(1), &mut bits);
+        bits.resize(bits.len().next_multiple_of(8), false);
+        let mut input: String = bits.as_raw_slice().iter().map(|byte| format!("{byte:02X}")).collect();
+        input.push('\n');
+        input
+    }
+}
+
+/// Appends a packet containing `size` packets in total, including itself
+fn generate_packet(rng: &mut dyn RngCore, size: usize, bits: &mut BitVec<u8, Msb0>) {
+    push_bits(bits, rng.gen_range(0..8), 3);
+    if size == 1 {
+        push_bits(bits, 4, 3);
+        let groups = rng.gen_range(1..=3);
+        for group in (0..groups).rev() {
+            bits.push(group > 0);
+            push_bits(bits, rng.gen_range(0..16), 4);
+        }
+        return;
+    }
+
+    // comparisons need exactly two packets, and products only get one or two literals so they can't overflow
+    let types: &[usize] = if size <= 3 {
+        &[0, 1, 2, 3, 5, 6, 7]
+    } else {
+        &[0, 2, 3, 5, 6, 7]
+    };
+    let typ = types[rng.gen_range(0..types.len())];
+    let sizes = match typ {
+        1 => vec![1; size - 1],
+        5..=7 if size > 2 => split(rng, size - 1, 2),
+        _ => {
+            let parts = rng.gen_range(2..=4).min(size - 1);
+            split(rng, size - 1, parts)
+        }
+    };
+    let typ = if sizes.len() != 2 && typ >= 5 { 0 } else { typ };
+    push_bits(bits, typ, 3);
+
+    let mut children = BitVec::<u8, Msb0>::new();
+    for size in &sizes {
+        generate_packet(rng, *size, &mut children);
+    }
+    if children.len() < 1 << 15 && rng.gen_bool(0.5) {
+        bits.push(false);
+        push_bits(bits, children.len(), 15);
+    } else {
+        bits.push(true);
+        push_bits(bits, sizes.len(), 11);
+    }
+    bits.extend_from_bitslice(&children);
+}
+
+/// Splits `total` into `parts` sizes of at least one each
+fn split(rng: &mut dyn RngCore, total: usize, parts: usize) -> Vec<usize> {
+    let mut sizes = vec![1; parts];
+    for _ in parts..total {
+        sizes[rng.gen_range(0..parts)] += 1;
+    }
+    sizes
+}
+
+fn push_bits(bits: &mut BitVec<u8, Msb0>, value: usize, n: usize) {
+    bits.extend((0..n).rev().map(|i| value >> i & 1 == 1));
+}
+
+aoc::examples!(Day16);
+
+#[cfg(test)]
+mod tests {
+    use super::Day16;
+    use aoc::{
+        rand::{rngs::StdRng, Rng, SeedableRng},
+        Challenge, Generate, Parser,
+    };
+
+    const INPUT: &str = "C0015000016115A2E0802F182340";
+
+    #[test]
+    fn parse() {
+        let output = Day16::parse(INPUT).unwrap().1;
+        println!("{:?}", output);
+    }
+
+    #[test]
+    fn part_one() {
+        let output = Day16::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_one(), 23);
+    }
+
+    #[test]
+    fn part_two() {
+        let output = Day16::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_two(), 46);
+    }
+
+    #[test]
+    fn generated() {
+        let input = Day16::generate(&mut StdRng::seed_from_u64(16), 200);
+        let bits: Vec<usize> = input
+            .trim_end()
+            .chars()
+            .flat_map(|c| {
+                let digit = c.to_digit(16).unwrap() as usize;
+                (0..4).rev().map(move |i| digit >> i & 1)
+            })
+            .collect();
+        let (versions, value) = decode(&bits, &mut 0);
+
+        let output = Day16::parse(&input).unwrap().1;
+        assert_eq!(output.part_one(), versions);
+        assert_eq!(output.part_two(), value);
+    }
+
+    /// A straightforward decoder to check against, returning the version sum and value of the packet at `at`
+    fn decode(bits: &[usize], at: &mut usize) -> (usize, usize) {
+        let mut read = |n| {
+            *at += n;
+            bits[*at - n..*at].iter().fold(0, |v, &b| v << 1 | b)
+        };
+        let version = read(3);
+        let kind = read(3);
+        if kind == 4 {
+            let mut value = 0;
+            loop {
+                let group = read(5);
+                value = value << 4 | group & 0xf;
+                if group < 16 {
+                    return (version, value);
+                }
+            }
+        }
+
+        let mut subpackets = vec![];
+        if read(1) == 0 {
+            let end = read(15) + *at;
+            while *at < end {
+                subpackets.push(decode(bits, at));
+            }
+        } else {
+            for _ in 0..read(11) {
+                subpackets.push(decode(bits, at));
+            }
+        }
+
+        let versions = version + subpackets.iter().map(|p| p.0).sum::<usize>();
+        let values = subpackets.iter().map(|p| p.1);
+        let value = match kind {
+            0 => values.sum(),
+            1 => values.product(),
+            2 => values.min().unwrap(),
+            3 => values.max().unwrap(),
+            5 => (subpackets[0].1 > subpackets[1].1) as usize,
+            6 => (subpackets[0].1 < subpackets[1].1) as usize,
+            _ => (subpackets[0].1 == subpackets[1].1) as usize,
+        };
+        (versions, value)
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let size = rng.gen_range(1..50);
+            aoc::parse::<Day16>(&Day16::generate(rng, size)).unwrap()
+        });
+    }
+}
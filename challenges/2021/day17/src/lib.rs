@@ -3,7 +3,7 @@ use std::{
     ops::RangeInclusive,
 };
 
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, Parser as ChallengeParser, Unparse};
 use nom::{
     bytes::complete::tag,
     character::complete::digit1,
@@ -29,17 +29,32 @@ impl<'i> ChallengeParser<'i> for Day17 {
     }
 }
 
+impl Unparse for Day17 {
+    fn unparse(&self) -> String {
+        let Self { x, y } = self;
+        format!(
+            "target area: x={}..{}, y={}..{}\n",
+            x.start(),
+            x.end(),
+            y.start(),
+            y.end()
+        )
+    }
+}
+
 impl Challenge for Day17 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day17";
+    const YEAR: usize = 2021;
 
-    fn part_one(self) -> usize {
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn part_one(&self) -> usize {
         let mut peaks = BTreeMap::new();
-        for x in 0..100 {
-            for y in 0..2000 {
-                let record = self.shoot((x, y));
-                if let Some(max) = record.into_iter().map(|(_, y)| y).max() {
-                    peaks.insert((x, y), max);
-                }
+        for (x, y) in self.velocities() {
+            let record = self.shoot((x, y));
+            if let Some(max) = record.into_iter().map(|(_, y)| y).max() {
+                peaks.insert((x, y), max);
             }
         }
         let (_, y) = peaks.into_iter().max_by_key(|(_, y)| *y).unwrap();
@@ -47,14 +62,12 @@ impl Challenge for Day17 {
         y as usize
     }
 
-    fn part_two(self) -> usize {
+    fn part_two(&self) -> usize {
         let mut peaks = BTreeSet::new();
-        for x in 0..150 {
-            for y in -163..4000 {
-                let record = self.shoot((x, y));
-                if !record.is_empty() {
-                    peaks.insert((x, y));
-                }
+        for (x, y) in self.velocities() {
+            let record = self.shoot((x, y));
+            if !record.is_empty() {
+                peaks.insert((x, y));
             }
         }
 
@@ -63,6 +76,14 @@ impl Challenge for Day17 {
 }
 
 impl Day17 {
+    /// Every starting velocity that could reach the target, assuming it's below and to the right.
+    /// Any faster to the right overshoots on the first step, and any faster upwards
+    /// comes back down through y=0 and overshoots on the next
+    fn velocities(&self) -> impl Iterator<Item = (isize, isize)> {
+        let (x, y) = (*self.x.end(), *self.y.start());
+        (0..=x).flat_map(move |vx| (y..=-y).map(move |vy| (vx, vy)))
+    }
+
     fn shoot(&self, mut vel: (isize, isize)) -> Vec<(isize, isize)> {
         let mut pos = (0, 0);
         let mut record = vec![];
@@ -85,10 +106,12 @@ impl Day17 {
     }
 }
 
+aoc::examples!(Day17);
+
 #[cfg(test)]
 mod tests {
     use super::Day17;
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser};
 
     const INPUT: &str = "target area: x=20..30, y=-10..-5";
 
@@ -110,4 +133,15 @@ mod tests {
         output.shoot((6, 0));
         assert_eq!(output.part_two(), 112);
     }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let [x, y] = [rng.gen_range(1..200), rng.gen_range(-200..-1)];
+            Day17 {
+                x: x..=x + rng.gen_range(0..50),
+                y: y..=y + rng.gen_range(0..50),
+            }
+        });
+    }
 }
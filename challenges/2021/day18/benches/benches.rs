@@ -0,0 +1,47 @@
+use aoc::{
+    rand::{rngs::StdRng, SeedableRng},
+    Challenge, Generate, Parser,
+};
+use aoc2021_day18::Day18;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+pub fn day18(c: &mut Criterion) {
+    let mut group = c.benchmark_group(Day18::NAME);
+
+    let input = include_str!("../input.txt");
+    let challenge = Day18::parse(input).unwrap().1;
+
+    group.bench_function("parse", |b| b.iter(|| Day18::parse(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| challenge.part_one()));
+    group.bench_function("part2", |b| b.iter(|| challenge.part_two()));
+
+    group.finish();
+}
+
+pub fn day18_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("{}/scaling", Day18::NAME));
+    let mut rng = StdRng::seed_from_u64(18);
+
+    // part two adds every pair, so the biggest input takes seconds an iteration
+    group.sample_size(10);
+    // up to ten times the real input's 100 numbers
+    for size in [10, 100, 1000] {
+        let input = Day18::generate(&mut rng, size);
+        let challenge = Day18::parse(&input).unwrap().1;
+
+        group.bench_with_input(BenchmarkId::new("parse", size), &input, |b, input| {
+            b.iter(|| Day18::parse(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part1", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_one())
+        });
+        group.bench_with_input(BenchmarkId::new("part2", size), &challenge, |b, challenge| {
+            b.iter(|| challenge.part_two())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day18, day18_scaling);
+criterion_main!(benches);
@@ -1,4 +1,7 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{
+    rand::{Rng, RngCore},
+    Challenge, Generate, Parser as ChallengeParser, Unparse,
+};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -95,8 +98,8 @@ impl Tree {
             Tree::Value(v) => {
                 let c = *v >= 10;
                 if c {
-                    let x = (*v + 0) / 2; // floor div
-                    let y = (*v + 1) / 2; // ceil div
+                    let x = *v / 2;
+                    let y = v.div_ceil(2);
                     *self = Tree::new_pair(Tree::Value(x), Tree::Value(y));
                 }
                 c
@@ -114,21 +117,33 @@ impl<'i> ChallengeParser<'i> for Day18 {
     }
 }
 
+impl Unparse for Day18 {
+    fn unparse(&self) -> String {
+        // the debug form of a tree is already snailfish notation
+        self.0.iter().map(|tree| format!("{tree:?}\n")).collect()
+    }
+}
+
 impl Challenge for Day18 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day18";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
 
-    fn part_one(self) -> usize {
+    fn part_one(&self) -> usize {
         // sum them all in order
-        let tree = self.0.into_iter().reduce(Tree::add).unwrap();
+        let tree = self.0.iter().cloned().reduce(Tree::add).unwrap();
         tree.eval()
     }
 
-    fn part_two(mut self) -> usize {
+    fn part_two(&self) -> usize {
         let mut max = 0;
 
         // try every pair and see which has the max magnitude
-        while let Some(x) = self.0.pop() {
-            for y in &self.0 {
+        let mut trees = self.0.clone();
+        while let Some(x) = trees.pop() {
+            for y in &trees {
                 max = max.max(Tree::add(x.clone(), y.clone()).eval());
                 max = max.max(Tree::add(y.clone(), x.clone()).eval());
             }
@@ -138,10 +153,49 @@ impl Challenge for Day18 {
     }
 }
 
+impl Generate for Day18 {
+    /// `size` snailfish numbers, all already reduced
+    fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            generate_tree(rng, 0, &mut input);
+            input.push('\n');
+        }
+        input
+    }
+}
+
+fn generate_tree(rng: &mut dyn RngCore, depth: usize, out: &mut String) {
+    // every number is a pair, and a reduced one has no pairs nested inside four others
+    if depth == 4 || (depth > 0 && rng.gen_bool(0.4)) {
+        out.push(char::from(b'0' + rng.gen_range(0..10)));
+    } else {
+        out.push('[');
+        generate_tree(rng, depth + 1, out);
+        out.push(',');
+        generate_tree(rng, depth + 1, out);
+        out.push(']');
+    }
+}
+
+impl std::fmt::Debug for Tree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pair(x, y) => write!(f, "[{:?},{:?}]", x, y),
+            Self::Value(v) => write!(f, "{:?}", v),
+        }
+    }
+}
+
+aoc::examples!(Day18);
+
 #[cfg(test)]
 mod tests {
     use super::Day18;
-    use aoc::{Challenge, Parser};
+    use aoc::{
+        rand::{rngs::StdRng, Rng, SeedableRng},
+        Challenge, Generate, Parser,
+    };
 
     const INPUT: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
 [[[5,[2,8]],4],[5,[[9,9],0]]]
@@ -172,13 +226,78 @@ mod tests {
         let output = Day18::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 3993);
     }
-}
 
-impl std::fmt::Debug for Tree {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Pair(x, y) => write!(f, "[{:?},{:?}]", x, y),
-            Self::Value(v) => write!(f, "{:?}", v),
+    #[test]
+    fn generated() {
+        let input = Day18::generate(&mut StdRng::seed_from_u64(18), 100);
+        // each number as its digits and how deeply nested each one is, rather than a tree
+        let numbers: Vec<Vec<(usize, usize)>> = input
+            .lines()
+            .map(|line| {
+                let mut depth = 0;
+                let mut digits = vec![];
+                for c in line.chars() {
+                    match c {
+                        '[' => depth += 1,
+                        ']' => depth -= 1,
+                        ',' => {}
+                        _ => digits.push((c.to_digit(10).unwrap() as usize, depth)),
+                    }
+                }
+                digits
+            })
+            .collect();
+        let sum = numbers.iter().cloned().reduce(add).unwrap();
+        let largest = (0..numbers.len())
+            .flat_map(|i| (0..numbers.len()).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| magnitude(add(numbers[i].clone(), numbers[j].clone())))
+            .max()
+            .unwrap();
+
+        let output = Day18::parse(&input).unwrap().1;
+        assert_eq!(output.part_one(), magnitude(sum));
+        assert_eq!(output.part_two(), largest);
+    }
+
+    fn add(a: Vec<(usize, usize)>, b: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+        let mut sum: Vec<_> = a.into_iter().chain(b).map(|(n, depth)| (n, depth + 1)).collect();
+        loop {
+            // a regular number nested inside four pairs is always the left of a pair of regular numbers
+            if let Some(i) = sum.iter().position(|&(_, depth)| depth > 4) {
+                let ((left, depth), (right, _)) = (sum[i], sum.remove(i + 1));
+                if i > 0 {
+                    sum[i - 1].0 += left;
+                }
+                if let Some(next) = sum.get_mut(i + 1) {
+                    next.0 += right;
+                }
+                sum[i] = (0, depth - 1);
+            } else if let Some(i) = sum.iter().position(|&(n, _)| n >= 10) {
+                let (n, depth) = sum[i];
+                sum[i] = (n / 2, depth + 1);
+                sum.insert(i + 1, (n.div_ceil(2), depth + 1));
+            } else {
+                return sum;
+            }
+        }
+    }
+
+    fn magnitude(mut number: Vec<(usize, usize)>) -> usize {
+        // the first of the most deeply nested numbers always pairs with the one after it
+        while number.len() > 1 {
+            let deepest = number.iter().map(|&(_, depth)| depth).max().unwrap();
+            let i = number.iter().position(|&(_, depth)| depth == deepest).unwrap();
+            let (right, _) = number.remove(i + 1);
+            number[i] = (3 * number[i].0 + 2 * right, deepest - 1);
         }
+        number[0].0
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let size = rng.gen_range(1..50);
+            aoc::parse::<Day18>(&Day18::generate(rng, size)).unwrap()
+        });
     }
 }
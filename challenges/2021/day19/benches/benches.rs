@@ -1,6 +1,6 @@
 use aoc::{Challenge, Parser};
-use criterion::{black_box, criterion_group, criterion_main, Criterion, BatchSize};
-use day19::Day19;
+use aoc2021_day19::Day19;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 pub fn day19(c: &mut Criterion) {
     let mut group = c.benchmark_group(Day19::NAME);
@@ -8,13 +8,11 @@ pub fn day19(c: &mut Criterion) {
     let input = include_str!("../input.txt");
     let challenge = Day19::parse(input).unwrap().1;
 
+    // parsing aligns the scanners, so the parts only read the answers off the map
     group.bench_function("parse", |b| b.iter(|| Day19::parse(black_box(input))));
-    group.bench_function("part1", |b| {
-        b.iter_batched(|| challenge.clone(), Challenge::part_one, BatchSize::SmallInput)
-    });
-    group.bench_function("part2", |b| {
-        b.iter_batched(|| challenge.clone(), Challenge::part_two, BatchSize::SmallInput)
-    });
+    group.bench_function("align", |b| b.iter(|| challenge.align()));
+    group.bench_function("part1", |b| b.iter(|| challenge.part_one()));
+    group.bench_function("part2", |b| b.iter(|| challenge.part_two()));
 
     group.finish();
 }
@@ -0,0 +1,251 @@
+use std::collections::BTreeSet;
+
+use aoc::{Challenge, ChallengeError, Parser as ChallengeParser, Unparse};
+use array_bin_ops::Array;
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::{digit1, line_ending},
+    combinator::{map_res, opt, recognize},
+    sequence::{preceded, tuple},
+    IResult, Parser,
+};
+use parsers::ParserExt;
+
+type Point = [i32; 3];
+
+fn number(input: &str) -> IResult<&str, i32> {
+    map_res(recognize(tuple((opt(tag("-")), digit1))), |x: &str| x.parse::<i32>())(input)
+}
+
+fn parse_point(input: &str) -> IResult<&str, Point> {
+    number.separated_array(tag(",")).parse(input)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Scanner(Vec<Point>);
+
+impl Scanner {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        preceded(
+            tag("--- scanner ").and(take_until("\n")).and(line_ending),
+            parse_point.separated_list0(line_ending).map(Self),
+        )
+        .parse(input)
+    }
+}
+
+/// Each scanner's readings, relative to itself
+#[derive(Debug, PartialEq, Clone)]
+pub struct Day19 {
+    scanners: Vec<Scanner>,
+    /// Both parts need the whole map, so it's aligned while parsing
+    map: Result<Map, ChallengeError>,
+}
+
+/// The scanners' positions and every beacon they found, all relative to the first scanner
+#[derive(Debug, PartialEq, Clone)]
+pub struct Map {
+    scanners: Vec<Point>,
+    beacons: BTreeSet<Point>,
+}
+
+impl<'i> ChallengeParser<'i> for Day19 {
+    fn parse(input: &'i str) -> IResult<&'i str, Self> {
+        Scanner::parse.separated_list1(tag("\n\n")).map(Self::new).parse(input)
+    }
+}
+
+impl Unparse for Day19 {
+    fn unparse(&self) -> String {
+        let scanners = self.scanners.iter().enumerate().map(|(i, Scanner(points))| {
+            let points: String = points.iter().map(|[x, y, z]| format!("{x},{y},{z}\n")).collect();
+            format!("--- scanner {i} ---\n{points}")
+        });
+        scanners.collect::<Vec<_>>().join("\n")
+    }
+}
+
+impl Challenge for Day19 {
+    const NAME: &'static str = "day19";
+    const YEAR: usize = 2021;
+
+    type Output1 = Result<usize, ChallengeError>;
+    type Output2 = Result<usize, ChallengeError>;
+
+    fn part_one(&self) -> Result<usize, ChallengeError> {
+        Ok(self.map()?.beacons.len())
+    }
+
+    fn part_two(&self) -> Result<usize, ChallengeError> {
+        let scanners = &self.map()?.scanners;
+        let mut distances = vec![];
+        for (i, a) in scanners.iter().enumerate() {
+            for b in &scanners[i..] {
+                let dist = Array(*a).zip_map(*b, |a, b| a.abs_diff(b));
+                distances.push(dist[0] + dist[1] + dist[2]);
+            }
+        }
+
+        Ok(distances.into_iter().max().unwrap_or_default() as usize)
+    }
+}
+
+impl Day19 {
+    fn new(scanners: Vec<Scanner>) -> Self {
+        let map = align(&scanners);
+        Self { scanners, map }
+    }
+
+    fn map(&self) -> Result<&Map, ChallengeError> {
+        self.map.as_ref().map_err(Clone::clone)
+    }
+
+    /// Aligns the scanners again, for benchmarking what parsing does up front
+    pub fn align(&self) -> Result<Map, ChallengeError> {
+        align(&self.scanners)
+    }
+}
+
+/// Works out where each scanner is by rotating its readings until at least 12 line up with the beacons found so far.
+/// Fails if some scanners never overlap the rest
+fn align(scanners: &[Scanner]) -> Result<Map, ChallengeError> {
+    let mut positions = Vec::<Point>::new();
+    let mut beacons = BTreeSet::<Point>::new(); // beacons will need constant look up
+    let (first, rest) = scanners.split_first().expect("parsing needs at least one scanner");
+    beacons.extend(&first.0);
+    positions.push([0, 0, 0]);
+    let mut unaligned = rest.to_vec();
+
+    loop {
+        let remaining = unaligned.len();
+        let mut repeat = vec![];
+
+        for mut scanner in unaligned {
+            let mut offset = None;
+
+            let mut i = 0;
+            while i < 48 {
+                offset = intersects(&beacons, &scanner.0);
+                if offset.is_some() {
+                    break;
+                }
+
+                // rotate perspective afterwards
+                scanner.0.iter_mut().for_each(|s| rotate(s, i));
+
+                i += 1;
+            }
+
+            if let Some(offset) = offset {
+                beacons.extend(
+                    scanner
+                        .0
+                        .into_iter()
+                        .map(|[a, b, c]| [offset[0] + a, offset[1] + b, offset[2] + c]),
+                );
+                aoc::tracing::debug!(?offset, beacons = beacons.len(), "aligned a scanner");
+                positions.push(offset);
+            } else {
+                repeat.push(scanner);
+            }
+        }
+
+        if repeat.is_empty() {
+            break;
+        }
+        // a whole pass without aligning anything means the rest never will
+        if repeat.len() == remaining {
+            return Err(ChallengeError::NoSolution);
+        }
+
+        unaligned = repeat;
+    }
+
+    Ok(Map {
+        scanners: positions,
+        beacons,
+    })
+}
+
+fn rotate(s: &mut [i32], i: usize) {
+    s.rotate_right(1);
+    if i.is_multiple_of(3) {
+        s.swap(1, 2);
+    }
+    if i.is_multiple_of(6) {
+        s[2] *= -1;
+    }
+    if i.is_multiple_of(12) {
+        s[1] *= -1;
+    }
+    if i.is_multiple_of(24) {
+        s[0] *= -1;
+    }
+}
+
+fn intersects(beacons: &BTreeSet<Point>, found: &[Point]) -> Option<Point> {
+    for b in beacons {
+        for f1 in found {
+            let offset = [b[0] - f1[0], b[1] - f1[1], b[2] - f1[2]];
+
+            let mut count = 0;
+
+            for (checked, f) in found.iter().enumerate() {
+                // the rest can't make up the 12 any more
+                if count + found.len() - checked < 12 {
+                    break;
+                }
+                let b = [offset[0] + f[0], offset[1] + f[1], offset[2] + f[2]];
+                if beacons.contains(&b) {
+                    count += 1;
+                }
+            }
+
+            if count >= 12 {
+                return Some(offset);
+            }
+        }
+    }
+    None
+}
+
+aoc::examples!(Day19);
+
+#[cfg(test)]
+mod tests {
+    use super::{Day19, Scanner};
+    use aoc::{rand::Rng, Challenge, Parser};
+
+    const INPUT: &str = include_str!("test.txt");
+
+    #[test]
+    fn parse() {
+        let output = Day19::parse(INPUT).unwrap().1;
+        println!("{:?}", output);
+        assert!(aoc::parse::<Day19>("").is_err());
+    }
+
+    #[test]
+    fn part_one() {
+        let output = Day19::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_one(), Ok(79));
+    }
+
+    #[test]
+    fn part_two() {
+        let output = Day19::parse(INPUT).unwrap().1;
+        assert_eq!(output.part_two(), Ok(3621));
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let mut scanners = vec![];
+            for _ in 0..rng.gen_range(1..5) {
+                let points = (0..rng.gen_range(1..20)).map(|_| [(); 3].map(|_| rng.gen_range(-1000..=1000)));
+                scanners.push(Scanner(points.collect()));
+            }
+            Day19::new(scanners)
+        });
+    }
+}
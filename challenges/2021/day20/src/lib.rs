@@ -1,4 +1,4 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, ChallengeError, Parser as ChallengeParser, Unparse, Visualize};
 use nom::{
     bytes::complete::{is_a, tag},
     character::complete::line_ending,
@@ -34,15 +34,30 @@ impl<'i> ChallengeParser<'i> for Day20<'i> {
     }
 }
 
+impl<'i> Unparse for Day20<'i> {
+    fn unparse(&self) -> String {
+        let image: String = self
+            .lines
+            .iter()
+            .map(|line| format!("{}\n", String::from_utf8_lossy(line)))
+            .collect();
+        format!("{}\n\n{image}", String::from_utf8_lossy(self.rules))
+    }
+}
+
 impl<'i> Challenge for Day20<'i> {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day20";
+    const YEAR: usize = 2021;
 
-    fn part_one(self) -> usize {
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn part_one(&self) -> usize {
         // part 1 requires 2 enchances
         self.enhance(2 - 1)
     }
 
-    fn part_two(self) -> usize {
+    fn part_two(&self) -> usize {
         // part 2 requires 50 enhances
         self.enhance(50 - 1)
     }
@@ -122,10 +137,37 @@ impl<'i> Day20<'i> {
     }
 }
 
+impl<'i> Visualize for Day20<'i> {
+    /// The image after each of part two's enhancements
+    fn frames(&self) -> Result<impl Iterator<Item = String> + '_, ChallengeError> {
+        let mut lines: Vec<Vec<u8>> = self.lines.iter().map(|line| line.to_vec()).collect();
+        let mut background = self.background;
+        Ok((1..=50).map(move |step| {
+            let image = Day20 {
+                rules: self.rules,
+                lines: lines.iter().map(Vec::as_slice).collect(),
+                background,
+            };
+            let (buf, b, w) = image.apply();
+            lines = buf.chunks_exact(w).map(<[u8]>::to_vec).collect();
+            background = b;
+
+            let mut frame = format!("enhancement {step}\n");
+            for line in &lines {
+                frame.push_str(std::str::from_utf8(line).unwrap());
+                frame.push('\n');
+            }
+            frame
+        }))
+    }
+}
+
+aoc::examples!(Day20);
+
 #[cfg(test)]
 mod tests {
     use super::Day20;
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser, Visualize};
 
     const INPUT: &str = include_str!("test.txt");
 
@@ -146,4 +188,27 @@ mod tests {
         let output = Day20::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 3351);
     }
+
+    #[test]
+    fn frames() {
+        let output = Day20::parse(INPUT).unwrap().1;
+        let second = output.frames().unwrap().nth(1).unwrap();
+        assert_eq!(second.matches('#').count(), 35);
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let size = rng.gen_range(1..20);
+            let mut pixel = || b".#"[rng.gen_range(0..2)];
+            let rules: Vec<u8> = (0..512).map(|_| pixel()).collect();
+            let mut line = || -> &[u8] { (0..size).map(|_| pixel()).collect::<Vec<_>>().leak() };
+            let lines = (0..size).map(|_| line()).collect();
+            Day20 {
+                rules: rules.leak(),
+                lines,
+                background: b'.',
+            }
+        });
+    }
 }
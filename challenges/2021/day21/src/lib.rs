@@ -1,7 +1,7 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, Parser as ChallengeParser, Unparse};
 use nom::IResult;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Day21([u8; 2]);
 
 impl<'i> ChallengeParser<'i> for Day21 {
@@ -14,17 +14,29 @@ impl<'i> ChallengeParser<'i> for Day21 {
     }
 }
 
+impl Unparse for Day21 {
+    fn unparse(&self) -> String {
+        let [a, b] = self.0;
+        format!("Player 1 starting position: {a}\nPlayer 2 starting position: {b}\n")
+    }
+}
+
 impl Challenge for Day21 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day21";
+    const YEAR: usize = 2021;
+
+    type Output1 = usize;
+    type Output2 = usize;
 
-    fn part_one(mut self) -> usize {
+    fn part_one(&self) -> usize {
+        let mut positions = self.0;
         let mut scores = [0, 0];
         let mut turns = 0;
         let mut roll = 6; // dice starts off at 1+2+3
         loop {
             let i = turns % 2;
-            let (pos, score) = play(self.0[i], scores[i], roll);
-            self.0[i] = pos;
+            let (pos, score) = play(positions[i], scores[i], roll);
+            positions[i] = pos;
             scores[i] = score;
 
             // Dice first rolls, a + a+1 + a+2 = (3a + 3).
@@ -40,7 +52,7 @@ impl Challenge for Day21 {
         }
     }
 
-    fn part_two(self) -> usize {
+    fn part_two(&self) -> usize {
         let [a, b] = self.count([0, 0]);
         a.max(b)
     }
@@ -87,10 +99,12 @@ fn play(mut pos: u8, mut score: usize, roll: u8) -> (u8, usize) {
     (pos, score)
 }
 
+aoc::examples!(Day21);
+
 #[cfg(test)]
 mod tests {
     use super::Day21;
-    use aoc::{Challenge, Parser};
+    use aoc::{rand::Rng, Challenge, Parser};
 
     const INPUT: &str = "Player 1 starting position: 4
 Player 2 starting position: 8
@@ -113,4 +127,10 @@ Player 2 starting position: 8
         let output = Day21::parse(INPUT).unwrap().1;
         assert_eq!(output.part_two(), 444356092776315);
     }
+
+    #[test]
+    fn roundtrip() {
+        // the parser only reads one digit, so there's no starting on 10
+        aoc::assert_roundtrip(|rng| Day21([rng.gen_range(1..10), rng.gen_range(1..10)]));
+    }
 }
@@ -1,6 +1,4 @@
-use std::collections::{BTreeSet, HashSet};
-
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, ChallengeError, Parser as ChallengeParser, Unparse};
 use nom::{
     branch::alt,
     bytes::complete::{tag, take},
@@ -17,6 +15,8 @@ struct Range {
     end: i32,
 }
 
+// the rest is for the parts, which aren't written yet
+#[allow(dead_code)]
 impl Range {
     fn parse(input: &str) -> IResult<&str, Self> {
         let number = map_res(recognize(tuple((opt(tag("-")), digit1))), |x: &str| x.parse::<i32>());
@@ -49,9 +49,7 @@ impl Range {
     /// 3..5 7..9 -> 3..5 7..5 7..9 (inner is invalid but that's ok since it's length 0)
     /// 3..9 5..7 -> 3..5 5..7 7..9
     fn split(self, other: Self) -> [Self; 3] {
-        if self.start > other.start {
-            return other.split(self);
-        } if self.start == other.start && self.end > other.end {
+        if (self.start, self.end) > (other.start, other.end) {
             return other.split(self);
         }
 
@@ -61,18 +59,9 @@ impl Range {
         let d = self.end.max(other.end);
 
         [
-            Self {
-                start: a,
-                end: b,
-            },
-            Self {
-                start: b,
-                end: c,
-            },
-            Self {
-                start: c,
-                end: d,
-            }
+            Self { start: a, end: b },
+            Self { start: b, end: c },
+            Self { start: c, end: d },
         ]
     }
 }
@@ -93,6 +82,7 @@ struct Cuboid {
     z: Range,
 }
 
+#[allow(dead_code)]
 impl Cuboid {
     fn parse(input: &str) -> IResult<&str, Self> {
         let assign = Range::parse.preceded_by(take(2usize));
@@ -128,17 +118,10 @@ impl Cuboid {
         let z = self.z.split(other.z);
 
         let mut out = [self; 27];
-        for i in 0..3 {
-            let index = i;
-            for j in 0..3 {
-                let index = index * 3 + j;
-                for k in 0..3 {
-                    let index = index * 3 + k;
-                    out[index] = Self {
-                        x: x[i],
-                        y: y[j],
-                        z: z[k],
-                    }
+        for (i, &x) in x.iter().enumerate() {
+            for (j, &y) in y.iter().enumerate() {
+                for (k, &z) in z.iter().enumerate() {
+                    out[(i * 3 + j) * 3 + k] = Self { x, y, z }
                 }
             }
         }
@@ -170,10 +153,31 @@ impl<'i> ChallengeParser<'i> for Day22 {
     }
 }
 
+impl Unparse for Day22 {
+    fn unparse(&self) -> String {
+        self.0
+            .iter()
+            .map(|state| {
+                let (switch, Cuboid { x, y, z }) = match state {
+                    State::On(cuboid) => ("on", cuboid),
+                    State::Off(cuboid) => ("off", cuboid),
+                };
+                // ranges are stored end exclusive
+                let [x, y, z] = [x, y, z].map(|range| format!("{}..{}", range.start, range.end - 1));
+                format!("{switch} x={x},y={y},z={z}\n")
+            })
+            .collect()
+    }
+}
+
 impl Challenge for Day22 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day22";
+    const YEAR: usize = 2021;
 
-    fn part_one(self) -> usize {
+    type Output1 = Result<usize, ChallengeError>;
+    type Output2 = Result<usize, ChallengeError>;
+
+    fn part_one(&self) -> Result<usize, ChallengeError> {
         // let mut points = BTreeSet::new();
         // let within = Range { start: -50, end: 50 };
         // let within = Cuboid {
@@ -209,17 +213,10 @@ impl Challenge for Day22 {
 
         // points.len()
 
-        todo!()
+        Err(ChallengeError::Unimplemented)
     }
 
-    fn part_two(mut self) -> usize {
-        let mut total = 0;
-        let mut states = self.0;
-
-        while let Some(i) = self.0.pop() {
-            if let Some()
-        }
-
+    fn part_two(&self) -> Result<usize, ChallengeError> {
         // while let Some(i) = self.0.pop() {
         //     let ci = match i {
         //         State::On(ci) => {
@@ -270,14 +267,16 @@ impl Challenge for Day22 {
 
         // add - sub
 
-        total
+        Err(ChallengeError::Unimplemented)
     }
 }
 
+aoc::examples!(Day22);
+
 #[cfg(test)]
 mod tests {
-    use super::Day22;
-    use aoc::{Challenge, Parser};
+    use super::{Cuboid, Day22, Range, State};
+    use aoc::{rand::Rng, Challenge, Parser};
 
     const INPUT: &str = "on x=-20..26,y=-36..17,z=-47..7
 on x=-20..33,y=-21..23,z=-26..28
@@ -308,9 +307,10 @@ on x=-41..9,y=-7..43,z=-33..15
     }
 
     #[test]
+    #[ignore = "part one is not implemented yet"]
     fn part_one() {
         let output = Day22::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 590784);
+        assert_eq!(output.part_one(), Ok(590784));
     }
 
     const INPUT2: &str = "on x=-5..47,y=-31..22,z=-19..33
@@ -376,16 +376,32 @@ off x=-93533..-4276,y=-16170..68771,z=-104985..-24507
 ";
 
     #[test]
+    #[ignore = "part two is not implemented yet"]
     fn part_two() {
-        let output = Day22::parse(
-            "on x=10..12,y=10..12,z=10..12
-on x=11..13,y=11..13,z=11..13
-off x=9..11,y=9..11,z=9..11
-on x=10..10,y=10..10,z=10..10
-",
-        )
-        .unwrap()
-        .1;
-        assert_eq!(output.part_two(), 2758514936282235);
+        let output = Day22::parse(INPUT2).unwrap().1;
+        assert_eq!(output.part_two(), Ok(2758514936282235));
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let mut steps = vec![];
+            for _ in 0..rng.gen_range(1..20) {
+                let [x, y, z] = [(); 3].map(|_| {
+                    let start = rng.gen_range(-50_000..50_000);
+                    Range {
+                        start,
+                        end: start + rng.gen_range(1..20_000),
+                    }
+                });
+                let cuboid = Cuboid { x, y, z };
+                steps.push(if rng.gen_bool(0.5) {
+                    State::On(cuboid)
+                } else {
+                    State::Off(cuboid)
+                });
+            }
+            Day22(steps)
+        });
     }
 }
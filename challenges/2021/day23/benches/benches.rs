@@ -1,6 +1,6 @@
 use aoc::{Challenge, Parser};
+use aoc2021_day23::Day23;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day23::Day23;
 
 pub fn day23(c: &mut Criterion) {
     let mut group = c.benchmark_group(Day23::NAME);
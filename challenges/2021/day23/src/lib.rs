@@ -1,6 +1,6 @@
 use core::fmt;
 
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, ChallengeError, Parser as ChallengeParser, Unparse, Visualize};
 use nom::IResult;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
@@ -107,6 +107,12 @@ impl<'i> ChallengeParser<'i> for Day23 {
     }
 }
 
+impl Unparse for Day23 {
+    fn unparse(&self) -> String {
+        self.0.to_string()
+    }
+}
+
 impl<const N: usize> Position<N> {
     const SUCCESS: Self = Self {
         rooms: [[State::A; N], [State::B; N], [State::C; N], [State::D; N]],
@@ -154,7 +160,7 @@ impl<const N: usize> Position<N> {
         // try move pieces in the rooms into the corridor
         'outer: for (i, room) in self.rooms.into_iter().enumerate() {
             let Some(room_pos) = room.iter().position(|&s| s != State::Empty) else {
-                continue 'outer
+                continue 'outer;
             };
             let state = room[room_pos];
             // if our room is empty/contains only our friends, we should not try to move
@@ -204,24 +210,34 @@ impl<const N: usize> Position<N> {
         distance
     }
 
-    fn solve(&self) -> usize {
-        let (_, cost) = pathfinding::directed::astar::astar(self, Position::successors, Position::heuristic, |x| {
+    /// The cheapest way to organise the amphipods, with every position along the way and its total energy
+    fn solve(&self) -> Result<(Vec<Self>, usize), ChallengeError> {
+        pathfinding::directed::astar::astar(self, Position::successors, Position::heuristic, |x| {
             x == &Position::SUCCESS
         })
-        .unwrap();
-        cost
+        .ok_or(ChallengeError::NoSolution)
     }
 }
 
 impl Challenge for Day23 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day23";
+    const YEAR: usize = 2021;
+
+    type Output1 = Result<usize, ChallengeError>;
+    type Output2 = Result<usize, ChallengeError>;
 
-    fn part_one(self) -> usize {
-        self.0.solve()
+    fn part_one(&self) -> Result<usize, ChallengeError> {
+        Ok(self.0.solve()?.1)
     }
 
-    fn part_two(self) -> usize {
-        // unfold
+    fn part_two(&self) -> Result<usize, ChallengeError> {
+        Ok(self.unfold().solve()?.1)
+    }
+}
+
+impl Day23 {
+    /// The full diagram for part two
+    fn unfold(&self) -> Position<4> {
         // #D#C#B#A#
         // #D#B#A#C#
         let Position { rooms, corridor } = self.0;
@@ -231,15 +247,24 @@ impl Challenge for Day23 {
             [rooms[2][0], State::B, State::A, rooms[2][1]],
             [rooms[3][0], State::A, State::C, rooms[3][1]],
         ];
-        let pos = Position { rooms, corridor };
-        pos.solve()
+        Position { rooms, corridor }
+    }
+}
+
+impl Visualize for Day23 {
+    /// Each move of the cheapest solution to the unfolded diagram
+    fn frames(&self) -> Result<impl Iterator<Item = String> + '_, ChallengeError> {
+        let (path, _) = self.unfold().solve()?;
+        Ok(path.into_iter().enumerate().map(|(i, pos)| format!("move {i}\n{pos}")))
     }
 }
 
+aoc::examples!(Day23);
+
 #[cfg(test)]
 mod tests {
-    use super::Day23;
-    use aoc::{Challenge, Parser};
+    use super::{Day23, Position, State};
+    use aoc::{rand::Rng, Challenge, Parser, Visualize};
 
     const INPUT: &str = "#############
 #...........#
@@ -257,12 +282,33 @@ mod tests {
     #[test]
     fn part_one() {
         let output = Day23::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 12521);
+        assert_eq!(output.part_one(), Ok(12521));
     }
 
     #[test]
     fn part_two() {
         let output = Day23::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 44169);
+        assert_eq!(output.part_two(), Ok(44169));
+    }
+
+    #[test]
+    fn frames() {
+        let output = Day23::parse(INPUT).unwrap().1;
+        let frames: Vec<String> = output.frames().unwrap().collect();
+        assert!(frames[0].starts_with("move 0\n#############\n#...........#\n###B#C#B#D###"));
+        let solved = "#...........#\n###A#B#C#D###\n  #A#B#C#D#\n  #A#B#C#D#\n  #A#B#C#D#\n  #########\n";
+        assert!(frames.last().unwrap().ends_with(solved));
+    }
+
+    #[test]
+    fn roundtrip() {
+        aoc::assert_roundtrip(|rng| {
+            let amphipods = [State::A, State::B, State::C, State::D];
+            let rooms = [[State::Empty; 2]; 4].map(|room| room.map(|_| amphipods[rng.gen_range(0..4)]));
+            Day23(Position {
+                rooms,
+                corridor: [State::Empty; 11],
+            })
+        });
     }
 }
@@ -1,4 +1,4 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, ChallengeError, Parser as ChallengeParser};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -140,9 +140,13 @@ impl<'i> ChallengeParser<'i> for Day24 {
 }
 
 impl Challenge for Day24 {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day24";
+    const YEAR: usize = 2021;
 
-    fn part_one(self) -> usize {
+    type Output1 = Result<usize, ChallengeError>;
+    type Output2 = Result<usize, ChallengeError>;
+
+    fn part_one(&self) -> Result<usize, ChallengeError> {
         let cfg = Config::new();
         let ctx = Context::new(&cfg);
         let mut state = State::new(&ctx);
@@ -162,14 +166,15 @@ impl Challenge for Day24 {
             .unwrap();
 
         state.solver.maximize(&input);
-        state.solver.check(&[]);
+        let status = state.solver.check(&[]);
+        aoc::tracing::debug!(?status, "maximised the model number");
 
-        let model = state.solver.get_model().unwrap();
-        let res = model.eval(&input, true).unwrap();
-        res.as_i64().unwrap() as usize
+        let model = state.solver.get_model().ok_or(ChallengeError::NoSolution)?;
+        let res = model.eval(&input, true).ok_or(ChallengeError::NoSolution)?;
+        Ok(res.as_u64().ok_or(ChallengeError::NoSolution)? as usize)
     }
 
-    fn part_two(self) -> usize {
+    fn part_two(&self) -> Result<usize, ChallengeError> {
         let cfg = Config::new();
         let ctx = Context::new(&cfg);
         let mut state = State::new(&ctx);
@@ -185,14 +190,17 @@ impl Challenge for Day24 {
         let input = state.inputs.into_iter().reduce(|a, b| a * 10_i64 + b).unwrap();
 
         state.solver.minimize(&input);
-        state.solver.check(&[]);
+        let status = state.solver.check(&[]);
+        aoc::tracing::debug!(?status, "minimised the model number");
 
-        let model = state.solver.get_model().unwrap();
-        let res = model.eval(&input, true).unwrap();
-        res.as_i64().unwrap() as usize
+        let model = state.solver.get_model().ok_or(ChallengeError::NoSolution)?;
+        let res = model.eval(&input, true).ok_or(ChallengeError::NoSolution)?;
+        Ok(res.as_u64().ok_or(ChallengeError::NoSolution)? as usize)
     }
 }
 
+aoc::examples!(Day24);
+
 #[cfg(test)]
 mod tests {
     use super::Day24;
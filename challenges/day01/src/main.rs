@@ -1,6 +0,0 @@
-use day01::Day01;
-
-fn main() {
-    let input = aoc::load::<Day01>();
-    aoc::run::<Day01>(&input);
-}
@@ -1,6 +0,0 @@
-use day02::Day02;
-
-fn main() {
-    let input = aoc::load::<Day02>();
-    aoc::run::<Day02>(&input);
-}
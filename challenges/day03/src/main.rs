@@ -1,6 +0,0 @@
-use day03::Day03;
-
-fn main() {
-    let input = aoc::load::<Day03>();
-    aoc::run::<Day03>(&input);
-}
@@ -1,6 +0,0 @@
-use day04::Day04;
-
-fn main() {
-    let input = aoc::load::<Day04>();
-    aoc::run::<Day04>(&input);
-}
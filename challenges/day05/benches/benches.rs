@@ -1,23 +0,0 @@
-use aoc::{Challenge, Parser};
-use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
-use day05::Day05;
-
-pub fn day05(c: &mut Criterion) {
-    let mut group = c.benchmark_group(Day05::NAME);
-
-    let input = include_str!("../input.txt");
-    let challenge = Day05::parse(input).unwrap().1;
-
-    group.bench_function("parse", |b| b.iter(|| Day05::parse(black_box(input))));
-    group.bench_function("part1", |b| {
-        b.iter_batched(|| challenge.clone(), Challenge::part_one, BatchSize::SmallInput)
-    });
-    group.bench_function("part2", |b| {
-        b.iter_batched(|| challenge.clone(), Challenge::part_two, BatchSize::SmallInput)
-    });
-
-    group.finish();
-}
-
-criterion_group!(benches, day05);
-criterion_main!(benches);
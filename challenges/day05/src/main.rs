@@ -1,6 +0,0 @@
-use day05::Day05;
-
-fn main() {
-    let input = aoc::load::<Day05>();
-    aoc::run::<Day05>(&input);
-}
@@ -1,6 +0,0 @@
-use day06::Day06;
-
-fn main() {
-    let input = aoc::load::<Day06>();
-    aoc::run::<Day06>(&input);
-}
@@ -1,6 +0,0 @@
-use day07::Day07;
-
-fn main() {
-    let input = aoc::load::<Day07>();
-    aoc::run::<Day07>(&input);
-}
@@ -1,6 +0,0 @@
-use day08::Day08;
-
-fn main() {
-    let input = aoc::load::<Day08>();
-    aoc::run::<Day08>(&input);
-}
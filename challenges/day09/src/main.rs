@@ -1,6 +0,0 @@
-use day09::Day09;
-
-fn main() {
-    let input = aoc::load::<Day09>();
-    aoc::run::<Day09>(&input);
-}
@@ -1,6 +0,0 @@
-use day10::Day10;
-
-fn main() {
-    let input = aoc::load::<Day10>();
-    aoc::run::<Day10>(&input);
-}
@@ -1,6 +0,0 @@
-use day11::Day11;
-
-fn main() {
-    let input = aoc::load::<Day11>();
-    aoc::run::<Day11>(&input);
-}
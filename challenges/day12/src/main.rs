@@ -1,6 +0,0 @@
-use day12::Day12;
-
-fn main() {
-    let input = aoc::load::<Day12>();
-    aoc::run::<Day12>(&input);
-}
@@ -1,6 +0,0 @@
-use day13::Day13;
-
-fn main() {
-    let input = aoc::load::<Day13>();
-    aoc::run::<Day13>(&input);
-}
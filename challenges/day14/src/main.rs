@@ -1,6 +0,0 @@
-use day14::Day14;
-
-fn main() {
-    let input = aoc::load::<Day14>();
-    aoc::run::<Day14>(&input);
-}
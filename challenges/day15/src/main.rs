@@ -1,6 +0,0 @@
-use day15::Day15;
-
-fn main() {
-    let input = aoc::load::<Day15>();
-    aoc::run::<Day15>(&input);
-}
@@ -1,6 +0,0 @@
-use day16::Day16;
-
-fn main() {
-    let input = aoc::load::<Day16>();
-    aoc::run::<Day16>(&input);
-}
@@ -1,6 +0,0 @@
-use day17::Day17;
-
-fn main() {
-    let input = aoc::load::<Day17>();
-    aoc::run::<Day17>(&input);
-}
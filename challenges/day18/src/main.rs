@@ -1,6 +0,0 @@
-use day18::Day18;
-
-fn main() {
-    let input = aoc::load::<Day18>();
-    aoc::run::<Day18>(&input);
-}
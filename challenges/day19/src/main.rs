@@ -1,6 +0,0 @@
-use day19::Day19;
-
-fn main() {
-    let input = aoc::load::<Day19>();
-    aoc::run::<Day19>(&input);
-}
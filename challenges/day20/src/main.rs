@@ -1,6 +0,0 @@
-use day20::Day20;
-
-fn main() {
-    let input = aoc::load::<Day20>();
-    aoc::run::<Day20>(&input);
-}
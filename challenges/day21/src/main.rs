@@ -1,6 +0,0 @@
-use day21::Day21;
-
-fn main() {
-    let input = aoc::load::<Day21>();
-    aoc::run::<Day21>(&input);
-}
@@ -1,6 +0,0 @@
-use day22::Day22;
-
-fn main() {
-    let input = aoc::load::<Day22>();
-    aoc::run::<Day22>(&input);
-}
@@ -1,6 +0,0 @@
-use day23::Day23;
-
-fn main() {
-    let input = aoc::load::<Day23>();
-    aoc::run::<Day23>(&input);
-}
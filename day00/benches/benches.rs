@@ -1,20 +1,20 @@
+use std::fs;
+
 use aoc::{Challenge, Parser};
-use criterion::{black_box, criterion_group, criterion_main, Criterion, BatchSize};
-use day00::Day00;
+use aoc{{YEAR}}_day00::Day00;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 pub fn day00(c: &mut Criterion) {
     let mut group = c.benchmark_group(Day00::NAME);
 
-    let input = include_str!("../input.txt");
-    let challenge = Day00::parse(input).unwrap().1;
+    // read when the bench runs rather than with include_str!, so a new day builds before its input is downloaded
+    let input = fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"))
+        .expect("download the input with `aoc run` before benchmarking");
+    let challenge = Day00::parse(&input).unwrap().1;
 
-    group.bench_function("parse", |b| b.iter(|| Day00::parse(black_box(input))));
-    group.bench_function("part1", |b| {
-        b.iter_batched(|| challenge.clone(), Challenge::part_one, BatchSize::SmallInput)
-    });
-    group.bench_function("part2", |b| {
-        b.iter_batched(|| challenge.clone(), Challenge::part_two, BatchSize::SmallInput)
-    });
+    group.bench_function("parse", |b| b.iter(|| Day00::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| challenge.part_one()));
+    group.bench_function("part2", |b| b.iter(|| challenge.part_two()));
 
     group.finish();
 }
@@ -1,4 +1,4 @@
-use aoc::{Challenge, Parser as ChallengeParser};
+use aoc::{Challenge, ChallengeError, Parser as ChallengeParser};
 use nom::{bytes::complete::tag, IResult, Parser};
 
 #[derive(Debug, PartialEq, Clone)]
@@ -11,17 +11,23 @@ impl<'i> ChallengeParser<'i> for Day00<'i> {
 }
 
 impl<'i> Challenge for Day00<'i> {
-    const NAME: &'static str = env!("CARGO_PKG_NAME");
+    const NAME: &'static str = "day00";
+    const YEAR: usize = {{YEAR}};
 
-    fn part_one(self) -> usize {
-        todo!()
+    type Output1 = Result<usize, ChallengeError>;
+    type Output2 = Result<usize, ChallengeError>;
+
+    fn part_one(&self) -> Result<usize, ChallengeError> {
+        Err(ChallengeError::Unimplemented)
     }
 
-    fn part_two(self) -> usize {
-        todo!()
+    fn part_two(&self) -> Result<usize, ChallengeError> {
+        Err(ChallengeError::Unimplemented)
     }
 }
 
+aoc::examples!(Day00);
+
 #[cfg(test)]
 mod tests {
     use super::Day00;
@@ -36,14 +42,16 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "part one is not implemented yet"]
     fn part_one() {
         let output = Day00::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_one(), 0);
+        assert_eq!(output.part_one(), Ok(0));
     }
 
     #[test]
+    #[ignore = "part two is not implemented yet"]
     fn part_two() {
         let output = Day00::parse(INPUT).unwrap().1;
-        assert_eq!(output.part_two(), 0);
+        assert_eq!(output.part_two(), Ok(0));
     }
 }
@@ -1,6 +0,0 @@
-use day00::Day00;
-
-fn main() {
-    let input = aoc::load::<Day00>();
-    aoc::run::<Day00>(&input);
-}
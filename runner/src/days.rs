@@ -0,0 +1,39 @@
+use aoc::Day;
+
+/// Every challenge in the workspace, by year and then in calendar order
+pub static DAYS: &[Day] = &[
+    aoc::day!(aoc2021_day01::Day01, generate),
+    aoc::day!(aoc2021_day02::Day02, generate),
+    aoc::day!(aoc2021_day03::Day03),
+    aoc::day!(aoc2021_day04::Day04),
+    aoc::day!(aoc2021_day05::Day05, generate),
+    aoc::day!(aoc2021_day06::Day06),
+    aoc::day!(aoc2021_day07::Day07),
+    aoc::day!(aoc2021_day08::Day08),
+    aoc::day!(aoc2021_day09::Day09, generate),
+    aoc::day!(aoc2021_day10::Day10),
+    aoc::day!(aoc2021_day11::Day11, visualize),
+    aoc::day!(aoc2021_day12::Day12),
+    aoc::day!(aoc2021_day13::Day13, visualize),
+    aoc::day!(aoc2021_day14::Day14),
+    aoc::day!(aoc2021_day15::Day15, generate),
+    aoc::day!(aoc2021_day16::Day16, generate),
+    aoc::day!(aoc2021_day17::Day17),
+    aoc::day!(aoc2021_day18::Day18, generate),
+    aoc::day!(aoc2021_day19::Day19),
+    aoc::day!(aoc2021_day20::Day20, visualize),
+    aoc::day!(aoc2021_day21::Day21),
+    aoc::day!(aoc2021_day22::Day22),
+    aoc::day!(aoc2021_day23::Day23, visualize),
+    #[cfg(feature = "day24")]
+    aoc::day!(aoc2021_day24::Day24),
+];
+
+pub fn find(year: usize, number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.year == year && day.number() == number)
+}
+
+/// The most recent year with any registered days
+pub fn latest_year() -> usize {
+    DAYS.iter().map(|day| day.year).max().unwrap_or_default()
+}
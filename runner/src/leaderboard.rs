@@ -0,0 +1,85 @@
+use aoc::Leaderboard;
+
+/// The standings, or with a day, when each member solved its parts
+pub fn print(leaderboard: &Leaderboard, day: Option<u32>) {
+    match day {
+        Some(day) => print_day(leaderboard, day),
+        None => standings(leaderboard),
+    }
+}
+
+/// Each member's local score and stars, where `*` is both parts of a day and `+` is only the first
+fn standings(leaderboard: &Leaderboard) {
+    let days = 1..=25;
+    let tens: String = days
+        .clone()
+        .map(|day| if day < 10 { ' ' } else { digit(day / 10) })
+        .collect();
+    let units: String = days.clone().map(|day| digit(day % 10)).collect();
+    println!("{:10}{tens}", "");
+    println!("{:10}{units}", "");
+
+    for (rank, member) in leaderboard.ranked().into_iter().enumerate() {
+        let stars: String = days
+            .clone()
+            .map(|day| match (member.solved(day, 1), member.solved(day, 2)) {
+                (Some(_), Some(_)) => '*',
+                (Some(_), None) => '+',
+                _ => '.',
+            })
+            .collect();
+        println!(
+            "{:>3}) {:>4} {stars}  {}",
+            rank + 1,
+            member.local_score,
+            member.display_name()
+        );
+    }
+}
+
+fn digit(n: u32) -> char {
+    char::from_digit(n, 10).unwrap()
+}
+
+/// How long each member took to solve the day's parts after it unlocked, fastest to finish first
+fn print_day(leaderboard: &Leaderboard, day: u32) {
+    let Some(unlocked) = leaderboard.unlocked(day) else {
+        return eprintln!("The leaderboard's event {:?} isn't a year", leaderboard.event);
+    };
+    let mut members: Vec<_> = leaderboard
+        .members
+        .values()
+        .filter(|m| m.solved(day, 1).is_some())
+        .collect();
+    if members.is_empty() {
+        return println!("Nobody has solved day {day} yet");
+    }
+    members.sort_by_key(|m| (m.solved(day, 2).unwrap_or(i64::MAX), m.solved(day, 1)));
+
+    println!("{:5}{:>12}{:>12}  name", "", "part 1", "part 2");
+    for (rank, member) in members.into_iter().enumerate() {
+        let time = |level| {
+            member
+                .solved(day, level)
+                .map_or_else(String::new, |ts| elapsed(ts - unlocked))
+        };
+        println!(
+            "{:>3}) {:>12}{:>12}  {}",
+            rank + 1,
+            time(1),
+            time(2),
+            member.display_name()
+        );
+    }
+}
+
+/// Seconds as hh:mm:ss, with a day count in front once it's been more than a day
+fn elapsed(secs: i64) -> String {
+    let (days, secs) = (secs / 86400, secs % 86400);
+    let time = format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
@@ -0,0 +1,465 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    rand::{rngs::StdRng, SeedableRng},
+    Answers, AocClient, ChallengeError, Check, Day, Expected, Mode, Parts, RunOptions,
+};
+use clap::{Parser, Subcommand};
+use rayon::prelude::*;
+use report::Format;
+use tracing_subscriber::{filter::LevelFilter, fmt::format::FmtSpan, EnvFilter};
+
+mod days;
+mod leaderboard;
+mod new;
+mod play;
+mod report;
+mod status;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc::CountingAllocator = aoc::CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run Advent of Code challenges")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+    /// Show debug events from these days' solutions, by number
+    #[arg(long = "debug", global = true, value_name = "DAY")]
+    debug: Vec<u32>,
+    /// Extra log filters in the same form as RUST_LOG, such as `aoc=info` to time each step as it finishes
+    #[arg(long, global = true, value_name = "FILTER")]
+    log: Vec<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List every registered day
+    List,
+    /// Run and submit one or more days. Dry runs and checks run in parallel unless --serial is given
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// Which part to run: 1, 2, both, or auto to pick the unsolved one
+        #[arg(short, long, default_value = "auto")]
+        part: Parts,
+        /// Print the answers without submitting them
+        #[arg(long, group = "mode")]
+        dry_run: bool,
+        /// Submit even if the ledger says the answer is known
+        #[arg(long, group = "mode")]
+        force: bool,
+        /// Compare against the accepted answers in the ledger or answers.toml instead of submitting
+        #[arg(long, group = "mode")]
+        check: bool,
+        /// Play the day in the terminal instead of solving it, for days that support it
+        #[arg(long, group = "mode")]
+        visualize: bool,
+        /// Frames per second when visualising
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        fps: u32,
+    },
+    /// Time parsing and both parts of each day, in parallel unless --serial is given.
+    /// Build with --features count-allocations to count allocations as well
+    Time {
+        #[command(flatten)]
+        selection: Selection,
+        /// How to print the results
+        #[arg(long, value_enum, default_value = "table")]
+        format: Format,
+    },
+    /// Check each day's answers against answers.toml, in parallel unless --serial is given
+    Verify {
+        #[command(flatten)]
+        selection: Selection,
+    },
+    /// Create a new day from the day00 template
+    New {
+        /// The day to create, defaults to today during December
+        day: Option<u32>,
+        /// The event year, defaults to the most recent one
+        #[arg(short, long)]
+        year: Option<usize>,
+        /// Also download the input and puzzle description
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Download the latest puzzle descriptions into each day's README
+    Readme {
+        /// Days to update, by number
+        #[arg(short, long = "day", required = true)]
+        days: Vec<u32>,
+        /// The year of the days, defaults to the latest registered one
+        #[arg(short, long)]
+        year: Option<usize>,
+    },
+    /// Show how far each day of an event has got, from its files to whether its parts pass their examples
+    Status {
+        /// The event year, defaults to the latest registered one
+        #[arg(short, long)]
+        year: Option<usize>,
+    },
+    /// Show a private leaderboard, downloading it at most once every 15 minutes
+    Leaderboard {
+        /// The leaderboard's ID, the number at the end of its URL
+        id: u64,
+        /// The event year, defaults to the latest registered one
+        #[arg(short, long)]
+        year: Option<usize>,
+        /// Show how long each member took to solve this day instead of the standings
+        #[arg(short, long)]
+        day: Option<u32>,
+    },
+    /// Generate a random input for a day, for benchmarking or fuzzing its solution
+    Generate {
+        /// The day to generate for, by number
+        day: u32,
+        /// The year of the day, defaults to the latest registered one
+        #[arg(short, long)]
+        year: Option<usize>,
+        /// How big an input to make. What this counts depends on the day
+        #[arg(short, long, default_value_t = 100)]
+        size: usize,
+        /// Seed the generator to get the same input every time
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Write the input to this file instead of printing it
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+}
+
+#[derive(clap::Args)]
+struct Selection {
+    /// Only include days from this year. With --day, defaults to the latest registered year
+    #[arg(short, long)]
+    year: Option<usize>,
+    /// Days to include, by number
+    #[arg(short, long = "day")]
+    days: Vec<u32>,
+    /// Include every registered day
+    #[arg(long, conflicts_with = "days")]
+    all: bool,
+    /// Days to skip, by number
+    #[arg(long)]
+    skip: Vec<u32>,
+    /// Run one day at a time, for timings that aren't competing for the CPU
+    #[arg(long)]
+    serial: bool,
+}
+
+impl Selection {
+    /// The selected days, or every day of the year if none were picked
+    fn days(&self) -> Vec<&'static Day> {
+        let selected: Vec<_> = if self.days.is_empty() {
+            days::DAYS
+                .iter()
+                .filter(|day| self.year.is_none_or(|year| day.year == year))
+                .collect()
+        } else {
+            let year = self.year.unwrap_or_else(days::latest_year);
+            self.days.iter().map(|&n| find(year, n)).collect()
+        };
+        selected
+            .into_iter()
+            .filter(|day| !self.skip.contains(&day.number()))
+            .collect()
+    }
+
+    /// Calls `f` for each selected day on a thread pool, or one after another with `--serial`.
+    /// The results are in day order either way
+    fn map<T: Send>(&self, f: impl Fn(&'static Day) -> T + Sync + Send) -> Vec<T> {
+        let days = self.days();
+        if self.serial {
+            days.into_iter().map(f).collect()
+        } else {
+            days.into_par_iter().map(f).collect()
+        }
+    }
+}
+
+fn find(year: usize, number: u32) -> &'static Day {
+    days::find(year, number).unwrap_or_else(|| {
+        eprintln!("Day {number} of {year} is not registered");
+        std::process::exit(1)
+    })
+}
+
+/// Logs to stderr, showing only warnings unless RUST_LOG, --log or --debug ask for more
+fn init_tracing(debug: &[u32], log: &[String]) {
+    let mut filter = EnvFilter::builder()
+        .with_default_directive(LevelFilter::WARN.into())
+        .from_env_lossy();
+
+    // solutions log under their crate name, such as aoc2021_day24
+    let days = days::DAYS.iter().filter(|day| debug.contains(&day.number()));
+    let debug = days.map(|day| format!("aoc{}_{}=debug", day.year, day.name));
+    for directive in debug.chain(log.iter().cloned()) {
+        match directive.parse() {
+            Ok(parsed) => filter = filter.add_directive(parsed),
+            Err(e) => eprintln!("Ignoring log filter {directive}: {e}"),
+        }
+    }
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn main() {
+    let args = Args::parse();
+    init_tracing(&args.debug, &args.log);
+
+    match args.command {
+        Command::List => {
+            for day in days::DAYS {
+                println!("{} {:>2} {}", day.year, day.number(), day.name);
+            }
+        }
+        Command::Run {
+            selection,
+            part,
+            dry_run,
+            force,
+            check,
+            visualize,
+            fps,
+        } => {
+            if selection.days.is_empty() && !selection.all {
+                return eprintln!("Pick the days to run with --day, or run them all with --all");
+            }
+            let mode = match (dry_run, force, check) {
+                (true, _, _) => Mode::DryRun,
+                (_, true, _) => Mode::Force,
+                (_, _, true) => Mode::Check,
+                _ => Mode::Submit,
+            };
+            let options = RunOptions { parts: part, mode };
+
+            let client = AocClient::from_env();
+            if visualize {
+                for day in selection.days() {
+                    let _span = day.span().entered();
+                    let Some(visualizer) = day.visualize else {
+                        eprintln!("{day} has nothing to visualise");
+                        continue;
+                    };
+                    let played = match (day.load)(&client) {
+                        Ok(input) => play::play(visualizer, &input, fps).map_err(|e| e.to_string()),
+                        Err(e) => Err(e.to_string()),
+                    };
+                    if let Err(e) = played {
+                        eprintln!("Could not play {day}: {e}");
+                    }
+                }
+                return;
+            }
+
+            let run = |day| {
+                report(day, |lines| {
+                    let failed = match (day.load)(&client) {
+                        Ok(input) => (day.run)(&client, &input, &options, lines),
+                        Err(e) => {
+                            lines.push(format!("Could not load the input: {e}"));
+                            true
+                        }
+                    };
+                    verify_inputs(day, lines) | failed
+                })
+            };
+            // submissions go one at a time, since each can change what the ledger says about the next
+            let reports = match mode {
+                Mode::DryRun | Mode::Check => selection.map(run),
+                Mode::Submit | Mode::Force => selection.days().into_iter().map(run).collect(),
+            };
+            if print_reports(&reports) {
+                std::process::exit(1);
+            }
+        }
+        Command::Time { selection, format } => {
+            let client = AocClient::from_env();
+            let results = selection.map(|day| {
+                let _span = day.span().entered();
+                let input = (day.load)(&client).map_err(|e| e.to_string())?;
+                match panic::catch_unwind(|| (day.time)(&input)) {
+                    Ok(timings) => timings.map_err(|e| e.to_string()),
+                    Err(_) => Err("panicked".to_owned()),
+                }
+            });
+
+            let mut timings = vec![];
+            for (day, result) in selection.days().into_iter().zip(results) {
+                match result {
+                    Ok(t) => timings.push(t),
+                    Err(e) => eprintln!("Skipping {day}: {e}"),
+                }
+            }
+            report::print(&timings, format);
+        }
+        Command::Verify { selection } => {
+            let answers = match Answers::load(Path::new("answers.toml")) {
+                Ok(answers) => answers,
+                Err(e) => return eprintln!("{e}"),
+            };
+
+            let client = AocClient::from_env();
+            let reports = selection.map(|day| {
+                report(day, |lines| {
+                    let failed = match (day.load)(&client) {
+                        Ok(input) => verify(day, "input", &input, &answers.get(day.year, day.name), lines),
+                        Err(e) => {
+                            lines.push(format!("input: {e}"));
+                            true
+                        }
+                    };
+                    verify_inputs(day, lines) | failed
+                })
+            });
+
+            if print_reports(&reports) {
+                std::process::exit(1);
+            }
+        }
+        Command::New { day, year, fetch } => {
+            let today = aoc::today();
+            let day = match (day, today) {
+                (Some(day), _) | (None, (_, 12, day @ 1..=25)) => day,
+                (None, _) => return eprintln!("It's not advent yet, pass a day explicitly"),
+            };
+            // the latest event is this year's once December starts
+            let year = year.unwrap_or(match today {
+                (year, 12, _) => year as usize,
+                (year, _, _) => year as usize - 1,
+            });
+
+            let client = AocClient::from_env();
+            if let Err(e) = new::new(&client, year, day, fetch) {
+                eprintln!("Could not create day {day} of {year}: {e}");
+            }
+        }
+        Command::Readme { days, year } => {
+            let client = AocClient::from_env();
+            let year = year.unwrap_or_else(days::latest_year);
+            for n in days {
+                let day = find(year, n);
+                match day.update_readme(&client) {
+                    Ok(description) => println!("Updated {day} with {} parts", description.articles.len()),
+                    Err(e) => eprintln!("Could not update {day}: {e}"),
+                }
+            }
+        }
+        Command::Status { year } => status::print(year.unwrap_or_else(days::latest_year)),
+        Command::Leaderboard { id, year, day } => {
+            let client = AocClient::from_env();
+            let year = year.unwrap_or_else(days::latest_year);
+            match aoc::leaderboard(&client, year, id) {
+                Ok(board) => leaderboard::print(&board, day),
+                Err(e) => eprintln!("Could not get leaderboard {id}: {e}"),
+            }
+        }
+        Command::Generate {
+            day,
+            year,
+            size,
+            seed,
+            out,
+        } => {
+            let day = find(year.unwrap_or_else(days::latest_year), day);
+            let Some(generate) = day.generate else {
+                return eprintln!("{day} has no input generator");
+            };
+
+            let mut rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+            let input = generate(&mut rng, size);
+            match out {
+                Some(path) => {
+                    if let Err(e) = std::fs::write(&path, input) {
+                        eprintln!("Could not write {}: {e}", path.display());
+                    }
+                }
+                None => print!("{input}"),
+            }
+        }
+    }
+}
+
+/// What happened to one day of a run or verify, to print once every day is done
+struct Report {
+    day: &'static Day,
+    failed: bool,
+    elapsed: Duration,
+    lines: Vec<String>,
+}
+
+/// Times `f` for a day, turning a panic into a failure so one day can't take the rest down with it
+fn report(day: &'static Day, f: impl FnOnce(&mut Vec<String>) -> bool) -> Report {
+    let _span = day.span().entered();
+    let start = Instant::now();
+    let mut lines = vec![];
+    let failed = match panic::catch_unwind(AssertUnwindSafe(|| f(&mut lines))) {
+        Ok(failed) => failed,
+        Err(_) => {
+            lines.push("panicked".to_owned());
+            true
+        }
+    };
+    Report {
+        day,
+        failed,
+        elapsed: start.elapsed(),
+        lines,
+    }
+}
+
+/// Prints each day's status and lines in order, returning whether any of them failed
+fn print_reports(reports: &[Report]) -> bool {
+    for report in reports {
+        let status = if report.failed { "FAILED" } else { "ok" };
+        println!("{}: {status} in {:.1?}", report.day, report.elapsed);
+        report.lines.iter().for_each(|line| println!("\t{line}"));
+    }
+    reports.iter().any(|report| report.failed)
+}
+
+/// Solves a day for one input and compares against its accepted answers, adding a line per part to `lines`.
+/// Returns whether anything went wrong
+fn verify(day: &Day, label: &str, input: &str, expected: &Expected, lines: &mut Vec<String>) -> bool {
+    match (day.verify)(input, expected) {
+        Ok(checks) => {
+            let mut failed = false;
+            for (part, check) in ["one", "two"].iter().zip(checks) {
+                failed |= match &check {
+                    Check::Fail { .. } => true,
+                    Check::Unsolved(e) => *e != ChallengeError::Unimplemented,
+                    Check::Pass | Check::Unrecorded(_) => false,
+                };
+                lines.push(format!("{label} part {part}: {check}"));
+            }
+            failed
+        }
+        Err(e) => {
+            lines.push(format!("{label}: {e}"));
+            true
+        }
+    }
+}
+
+/// Verifies each of a day's extra inputs from its `inputs/` directory
+fn verify_inputs(day: &Day, lines: &mut Vec<String>) -> bool {
+    match day.inputs() {
+        Ok(inputs) => inputs.iter().fold(false, |failed, input| {
+            verify(day, &input.name, &input.text, &input.expected, lines) | failed
+        }),
+        Err(e) => {
+            lines.push(format!("inputs: {e}"));
+            true
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::{error::Error, fs, path::Path};
+
+use aoc::AocClient;
+
+/// Scaffolds `challenges/<year>/dayNN` from the `day00` template and registers it with the runner
+pub fn new(client: &AocClient, year: usize, day: u32, fetch: bool) -> Result<(), Box<dyn Error>> {
+    let name = format!("day{day:02}");
+    let dir = aoc::challenge_dir(year, &name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    // download first, so a failed request doesn't leave a half-created day behind
+    let downloads = if fetch {
+        Some((client.input(year, day)?, client.description(year, day)?.to_markdown()))
+    } else {
+        None
+    };
+
+    copy_template(Path::new("day00"), &dir, year, day)?;
+    if let Some((input, readme)) = downloads {
+        fs::write(dir.join("input.txt"), input)?;
+        fs::write(dir.join("README.md"), readme)?;
+    }
+    register(year, &name)?;
+
+    println!("Created {}", dir.display());
+    if fetch {
+        println!("Downloaded the input and description");
+    }
+    Ok(())
+}
+
+fn copy_template(from: &Path, to: &Path, year: usize, day: u32) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &target, year, day)?;
+        } else if entry.file_name() != "input.txt" {
+            let contents = fs::read_to_string(entry.path())?
+                .replace("{{YEAR}}", &year.to_string())
+                .replace("day00", &format!("day{day:02}"))
+                .replace("Day00", &format!("Day{day:02}"));
+            fs::write(target, contents)?;
+        }
+    }
+    Ok(())
+}
+
+/// Adds the new day to the runner's dependencies and registry, keeping both in year and day order
+fn register(year: usize, name: &str) -> Result<(), Box<dyn Error>> {
+    let challenge = format!("D{}", &name[1..]);
+    insert_line(
+        Path::new("runner/Cargo.toml"),
+        &format!("aoc{year}-{name} = {{ path = \"../challenges/{year}/{name}\" }}"),
+        |line| line.starts_with("aoc") && line.contains("-day"),
+    )?;
+    insert_line(
+        Path::new("runner/src/days.rs"),
+        &format!("    aoc::day!(aoc{year}_{name}::{challenge}),"),
+        |line| line.starts_with("    aoc::day!("),
+    )
+}
+
+fn insert_line(file: &Path, new: &str, is_entry: impl Fn(&str) -> bool) -> Result<(), Box<dyn Error>> {
+    let contents = fs::read_to_string(file)?;
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    let entries: Vec<usize> = (0..lines.len()).filter(|&i| is_entry(lines[i])).collect();
+    let last = *entries
+        .last()
+        .ok_or_else(|| format!("{} has no registered days", file.display()))?;
+    let index = entries.into_iter().find(|&i| lines[i] > new).unwrap_or(last + 1);
+    lines.insert(index, new);
+
+    fs::write(file, lines.join("\n") + "\n")?;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::{VisualizeError, Visualizer};
+
+/// Plays frames in the terminal, redrawing over the previous one at `fps` frames a second
+pub fn play(visualize: Visualizer, input: &str, fps: u32) -> Result<(), VisualizeError> {
+    let interval = Duration::from_secs(1) / fps;
+    let mut stdout = io::stdout().lock();
+    let mut next = Instant::now();
+
+    visualize(input, &mut |frame| {
+        thread::sleep(next.saturating_duration_since(Instant::now()));
+        next = Instant::now() + interval;
+
+        // move to the top left and clear everything below it
+        write!(stdout, "\x1b[H\x1b[J{frame}").unwrap();
+        stdout.flush().unwrap();
+    })
+}
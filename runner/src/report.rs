@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use aoc::{Allocations, ChallengeError, Timings};
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+pub fn print(timings: &[Timings], format: Format) {
+    match format {
+        Format::Table => {
+            table(timings);
+            if timings.iter().any(|t| t.allocations.is_some()) {
+                println!();
+                allocations_table(timings);
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(timings).unwrap()),
+        Format::Csv => {
+            println!(
+                "year,name,parse_ns,part_one_ns,part_two_ns,\
+                parse_allocs,parse_peak_bytes,part_one_allocs,part_one_peak_bytes,part_two_allocs,part_two_peak_bytes,\
+                part_one_error,part_two_error"
+            );
+            for t in timings {
+                let allocations = match t.allocations {
+                    Some(a) => [a.parse, a.part_one, a.part_two]
+                        .map(|a| format!("{},{}", a.count, a.peak_bytes))
+                        .join(","),
+                    None => ",,,,,".to_owned(),
+                };
+                let [one, two] = [&t.part_one_error, &t.part_two_error].map(|error| match error {
+                    // quoted, since errors can have commas in them
+                    Some(e) => format!("\"{}\"", e.to_string().replace('"', "\"\"")),
+                    None => String::new(),
+                });
+                println!(
+                    "{},{},{},{},{},{allocations},{one},{two}",
+                    t.year,
+                    t.name,
+                    t.parse.as_nanos(),
+                    t.part_one.as_nanos(),
+                    t.part_two.as_nanos()
+                );
+            }
+        }
+    }
+}
+
+fn table(timings: &[Timings]) {
+    println!(
+        "{:<12}{:>12}{:>12}{:>12}{:>12}",
+        "day", "parse", "part 1", "part 2", "total"
+    );
+    for t in timings {
+        let errors = [&t.part_one_error, &t.part_two_error];
+        row(
+            &format!("{}/{}", t.year, t.name),
+            t.parse,
+            t.part_one,
+            t.part_two,
+            errors,
+        );
+    }
+
+    let sum = |f: fn(&Timings) -> Duration| timings.iter().map(f).sum();
+    row(
+        "total",
+        sum(|t| t.parse),
+        sum(|t| t.part_one),
+        sum(|t| t.part_two),
+        [&None; 2],
+    );
+
+    // the table only has room to say that a part failed, so say why underneath
+    let mut failures = timings.iter().flat_map(|t| {
+        let errors = [("one", &t.part_one_error), ("two", &t.part_two_error)];
+        errors
+            .into_iter()
+            .filter_map(move |(part, error)| Some((t, part, error.as_ref()?)))
+    });
+    if let Some(first) = failures.next() {
+        println!();
+        for (t, part, error) in std::iter::once(first).chain(failures) {
+            println!("{}/{} part {part}: {error}", t.year, t.name);
+        }
+    }
+}
+
+/// A row of times, with `failed` in place of the time for parts that returned an error
+fn row(name: &str, parse: Duration, part_one: Duration, part_two: Duration, errors: [&Option<ChallengeError>; 2]) {
+    let total = parse + part_one + part_two;
+    let [one, two] = [(part_one, errors[0]), (part_two, errors[1])].map(|(time, error)| match error {
+        Some(_) => "failed".to_owned(),
+        None => format!("{time:.1?}"),
+    });
+    println!(
+        "{name:<12}{:>12}{one:>12}{two:>12}{:>12}",
+        format!("{parse:.1?}"),
+        format!("{total:.1?}"),
+    );
+}
+
+/// The number of allocations and peak heap use of each phase
+fn allocations_table(timings: &[Timings]) {
+    println!("{:<12}{:>24}{:>24}{:>24}", "day", "parse", "part 1", "part 2");
+    for t in timings {
+        let Some(a) = t.allocations else { continue };
+        println!(
+            "{:<12}{:>24}{:>24}{:>24}",
+            format!("{}/{}", t.year, t.name),
+            cell(a.parse),
+            cell(a.part_one),
+            cell(a.part_two),
+        );
+    }
+}
+
+fn cell(allocations: Allocations) -> String {
+    format!("{} allocs, {}", allocations.count, bytes(allocations.peak_bytes))
+}
+
+fn bytes(n: usize) -> String {
+    match n {
+        0..=1023 => format!("{n}B"),
+        1024..=1048575 => format!("{:.1}KiB", n as f64 / 1024.0),
+        _ => format!("{:.1}MiB", n as f64 / 1048576.0),
+    }
+}
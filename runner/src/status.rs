@@ -0,0 +1,196 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::Arc,
+};
+
+use aoc::{challenge_dir, Answers, ChallengeError, Check, Day, Description, Examples, Ledger};
+use rayon::prelude::*;
+
+use crate::days;
+
+/// How far along one day of the calendar is
+struct Status {
+    name: String,
+    krate: Crate,
+    input: bool,
+    /// How many parts the README describes, if there is one
+    readme: Option<usize>,
+    /// Whether the ledger or answers.toml has an accepted answer for each part
+    accepted: [bool; 2],
+    parts: [Part; 2],
+}
+
+enum Crate {
+    Missing,
+    /// The crate exists, but isn't in the runner's list of days
+    Unregistered,
+    Registered,
+}
+
+/// What running a part showed
+#[derive(Clone, Copy)]
+enum Part {
+    /// There was nothing to run it on
+    Untested,
+    /// It returned [`ChallengeError::Unimplemented`] or hit `todo!`
+    Unimplemented,
+    /// It runs, but there's no example to check it against
+    NoExample,
+    /// Whether it got its example right
+    Example(bool),
+    /// It panicked, returned an error, or couldn't parse what it ran on
+    Failed,
+}
+
+thread_local! {
+    /// Set while a part runs, since its panics show up in the table instead
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Prints a row per day of the event, running each part against its example to see how far it's got
+pub fn print(year: usize) {
+    let answers = Answers::load(Path::new("answers.toml")).unwrap_or_else(|e| {
+        eprintln!("Ignoring answers.toml: {e}");
+        Answers::default()
+    });
+
+    let hook = Arc::new(panic::take_hook());
+    let previous = Arc::clone(&hook);
+    panic::set_hook(Box::new(move |info| {
+        if !QUIET.get() {
+            previous(info)
+        }
+    }));
+    let statuses: Vec<_> = (1..=25)
+        .into_par_iter()
+        .map(|number| status(year, number, &answers))
+        .collect();
+    // back to whichever hook was there before, which the quiet one only borrowed
+    drop(panic::take_hook());
+    panic::set_hook(Arc::into_inner(hook).expect("the quiet hook was just dropped"));
+
+    println!(
+        "{:<12}{:<14}{:<7}{:<9}{:<10}{:<13}examples",
+        "day", "crate", "input", "readme", "accepted", "implemented"
+    );
+    for status in &statuses {
+        let krate = match status.krate {
+            Crate::Missing => "no",
+            Crate::Unregistered => "unregistered",
+            Crate::Registered => "yes",
+        };
+        let readme = match status.readme {
+            None | Some(0) => "no",
+            Some(1) => "1 part",
+            Some(_) => "2 parts",
+        };
+        let [one, two] = status.accepted.map(yes_no);
+        let [implemented_one, implemented_two] = status.parts.map(|part| match part {
+            Part::Untested => "?",
+            Part::Unimplemented => "no",
+            Part::NoExample | Part::Example(_) => "yes",
+            Part::Failed => "?",
+        });
+        let [example_one, example_two] = status.parts.map(|part| match part {
+            Part::Untested | Part::Unimplemented => "-",
+            Part::NoExample => "none",
+            Part::Example(true) => "pass",
+            Part::Example(false) => "FAIL",
+            Part::Failed => "error",
+        });
+        println!(
+            "{:<12}{krate:<14}{:<7}{readme:<9}{:<10}{:<13}{example_one}/{example_two}",
+            format!("{year}/{}", status.name),
+            yes_no(status.input),
+            format!("{one}/{two}"),
+            format!("{implemented_one}/{implemented_two}"),
+        );
+    }
+
+    let stars = statuses
+        .iter()
+        .flat_map(|status| status.accepted)
+        .filter(|&accepted| accepted)
+        .count();
+    println!("\n{stars} of 50 stars accepted");
+}
+
+fn yes_no(b: bool) -> &'static str {
+    if b {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+fn status(year: usize, number: u32, answers: &Answers) -> Status {
+    let name = format!("day{number:02}");
+    let expected = answers.get(year, &name);
+    let dir = challenge_dir(year, &name);
+    let day = days::find(year, number);
+
+    let krate = match day {
+        Some(_) => Crate::Registered,
+        None if dir.join("Cargo.toml").exists() => Crate::Unregistered,
+        None => Crate::Missing,
+    };
+    let input = fs::read_to_string(dir.join("input.txt")).ok();
+    let readme = fs::read_to_string(dir.join("README.md")).ok();
+    let ledger = Ledger::load(&dir.join("ledger.toml")).unwrap_or_else(|e| {
+        eprintln!("Ignoring the ledger for {year}/{name}: {e}");
+        Ledger::default()
+    });
+
+    let parts = match (day, Examples::load(&dir)) {
+        (Some(day), Ok(examples)) => [1, 2].map(|level| part(day, &examples, input.as_deref(), level)),
+        (Some(_), Err(e)) => {
+            eprintln!("Could not read the examples for {year}/{name}: {e}");
+            [Part::Failed; 2]
+        }
+        (None, _) => [Part::Untested; 2],
+    };
+
+    Status {
+        name,
+        krate,
+        input: input.is_some(),
+        readme: readme.map(|readme| Description::from_markdown(&readme).articles.len()),
+        accepted: [1, 2].map(|level| ledger.correct(level).or(expected.part(level)).is_some()),
+        parts,
+    }
+}
+
+/// Runs a part against its example, or against the real input just to see if it's implemented when there isn't one
+fn part(day: &Day, examples: &Examples, input: Option<&str>, level: usize) -> Part {
+    let example = examples.part(level);
+    let (input, expected) = match (&example.input, &example.answer, input) {
+        (Some(example), Some(answer), _) => (example.as_str(), Some(answer.as_str())),
+        (_, _, Some(input)) => (input, None),
+        _ => return Part::Untested,
+    };
+
+    QUIET.set(true);
+    let checked = panic::catch_unwind(AssertUnwindSafe(|| (day.check)(input, level, expected)));
+    QUIET.set(false);
+    match checked {
+        Ok(Ok(Check::Unsolved(ChallengeError::Unimplemented))) => Part::Unimplemented,
+        Err(payload) if is_todo(&*payload) => Part::Unimplemented,
+        Err(_) | Ok(Err(_)) | Ok(Ok(Check::Unsolved(_))) => Part::Failed,
+        Ok(Ok(_)) if expected.is_none() => Part::NoExample,
+        Ok(Ok(Check::Pass)) => Part::Example(true),
+        Ok(Ok(_)) => Part::Example(false),
+    }
+}
+
+/// Whether a panic came from `todo!` or `unimplemented!`
+fn is_todo(payload: &(dyn Any + Send)) -> bool {
+    let message = match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload.downcast_ref::<String>().map_or("", String::as_str),
+    };
+    message.starts_with("not yet implemented") || message.starts_with("not implemented")
+}